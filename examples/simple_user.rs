@@ -27,16 +27,8 @@ async fn main() {
 
     let friends = client.user().get_friends("unb_").await;
 
-    if friends.is_err() {
-        println!(
-            "couldn't find any friends for {} (err: {:?})",
-            user.name, friends
-        )
-    } else {
-        println!(
-            "{} has {} total friends",
-            user.name,
-            friends.unwrap().attr.total
-        );
+    match friends {
+        Ok(friends) => println!("{} has {} total friends", user.name, friends.attr.total),
+        Err(e) => println!("couldn't find any friends for {} (err: {:?})", user.name, e),
     }
 }
@@ -1,12 +1,35 @@
 //! Authentication-related functionality for Last.fm API.
 
 use std::collections::BTreeMap;
+use std::time::{Duration, Instant};
 
-use serde::Deserialize;
-use tracing::instrument;
+use reqwest::Url;
+use serde::{Deserialize, Serialize};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream, ToSocketAddrs};
+use tokio::task::JoinSet;
+use tracing::{debug, instrument};
 
 use crate::client::Client;
-use crate::error::Error;
+use crate::error::{Error, LastFmErrorCode};
+
+/// How long an `auth.getToken` token stays valid.
+pub const TOKEN_LIFETIME: Duration = Duration::from_secs(60 * 60);
+
+/// Path the [`CallbackListener`] expects Last.fm to redirect to.
+const CALLBACK_PATH: &str = "/callback";
+
+/// Upper bound on the size of a callback request head.
+const MAX_CALLBACK_REQUEST: usize = 8 * 1024;
+
+/// How long a connection to the [`CallbackListener`] may take to send its request head.
+///
+/// Browsers open speculative connections that may never carry a request.
+const CALLBACK_READ_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Page shown in the browser once the callback was received.
+const CALLBACK_PAGE: &str =
+    "<html><body>Authorization complete. You can close this window.</body></html>";
 
 /// Response from `auth.getToken`
 #[derive(Debug, Deserialize)]
@@ -21,7 +44,11 @@ pub struct SessionResponse {
 }
 
 /// A Last.fm session returned after authentication.
-#[derive(Debug, Deserialize)]
+///
+/// Pass it to [`Client::authenticate`] to make calls on behalf of the user.
+/// Session keys don't expire, so a [`SessionStore`](crate::session::SessionStore)
+/// can keep it for later runs.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
 pub struct Session {
     pub name: String,
     pub key: String,
@@ -54,4 +81,318 @@ impl Client {
         let response: SessionResponse = self.signed_post("auth.getSession", params).await?;
         Ok(response.session)
     }
+
+    /// Get a session key directly from a user's username and password.
+    ///
+    /// Intended for clients that can't send the user to a browser to approve a token.
+    /// The password is sent over HTTPS in the signed POST body and is never logged.
+    ///
+    /// [Last.fm docs](https://www.last.fm/api/show/auth.getMobileSession)
+    #[instrument(skip(self, password))]
+    pub async fn get_mobile_session(
+        &self,
+        username: &str,
+        password: &str,
+    ) -> Result<Session, Error> {
+        let mut params = BTreeMap::new();
+        params.insert("username".into(), username.into());
+        params.insert("password".into(), password.into());
+
+        let response: SessionResponse = self.signed_post("auth.getMobileSession", params).await?;
+        Ok(response.session)
+    }
+
+    /// Build the URL where the user approves `token` for this application.
+    pub fn desktop_auth_url(&self, token: &str) -> Url {
+        let mut url = self.auth_base_url().clone();
+        url.query_pairs_mut()
+            .append_pair("api_key", self.api_key())
+            .append_pair("token", token);
+        url
+    }
+
+    /// Build the URL where the user grants this application web access.
+    ///
+    /// After approval Last.fm redirects to `callback` (or the callback configured
+    /// for the API account if `None`) with a `token` query parameter, which can be
+    /// exchanged with [`Client::get_session`].
+    ///
+    /// [Last.fm docs](https://www.last.fm/api/webauth)
+    pub fn web_auth_url(&self, callback: Option<&str>) -> Url {
+        let mut url = self.auth_base_url().clone();
+        url.query_pairs_mut().append_pair("api_key", self.api_key());
+        if let Some(cb) = callback {
+            url.query_pairs_mut().append_pair("cb", cb);
+        }
+        url
+    }
+
+    /// Start the desktop authentication flow by requesting a fresh token.
+    ///
+    /// Send the user to [`DesktopAuth::url`], then call [`DesktopAuth::wait`]
+    /// to poll until they approve it.
+    ///
+    /// [Last.fm docs](https://www.last.fm/api/desktopauth)
+    #[instrument(skip(self))]
+    pub async fn desktop_auth(&self) -> Result<DesktopAuth<'_>, Error> {
+        let token = self.get_token().await?;
+
+        Ok(DesktopAuth {
+            client: self,
+            url: self.desktop_auth_url(&token),
+            token,
+            issued_at: Instant::now(),
+        })
+    }
+}
+
+/// Outcome of a single [`DesktopAuth::poll`].
+#[derive(Debug)]
+pub enum AuthPoll {
+    /// The user has not authorized the token yet.
+    Pending,
+    /// The user authorized the token.
+    Authorized(Session),
+}
+
+/// Backoff between `auth.getSession` attempts while waiting for the user.
+///
+/// The delay starts at `initial` and is multiplied by `multiplier` after each
+/// attempt, up to `max`.
+#[derive(Debug, Clone)]
+pub struct PollBackoff {
+    pub initial: Duration,
+    pub max: Duration,
+    pub multiplier: f64,
+}
+
+impl Default for PollBackoff {
+    fn default() -> Self {
+        Self {
+            initial: Duration::from_secs(2),
+            max: Duration::from_secs(30),
+            multiplier: 1.5,
+        }
+    }
+}
+
+impl PollBackoff {
+    /// Backoff with a fixed delay between attempts.
+    pub fn fixed(delay: Duration) -> Self {
+        Self {
+            initial: delay,
+            max: delay,
+            multiplier: 1.0,
+        }
+    }
+
+    fn next(&self, delay: Duration) -> Duration {
+        delay.mul_f64(self.multiplier.max(1.0)).min(self.max)
+    }
+}
+
+/// A pending desktop authentication, created by [`Client::desktop_auth`].
+#[derive(Debug)]
+pub struct DesktopAuth<'a> {
+    client: &'a Client,
+    token: String,
+    url: Url,
+    issued_at: Instant,
+}
+
+impl<'a> DesktopAuth<'a> {
+    /// The token waiting for approval.
+    pub fn token(&self) -> &str {
+        &self.token
+    }
+
+    /// The URL the user has to open to approve the token.
+    pub fn url(&self) -> &Url {
+        &self.url
+    }
+
+    /// When the token stops being valid, assuming it was issued when requested.
+    pub fn expires_at(&self) -> Instant {
+        self.issued_at + TOKEN_LIFETIME
+    }
+
+    /// Check once whether the user has authorized the token.
+    ///
+    /// Returns [`Error::TokenExpired`] if Last.fm reports the token as expired.
+    pub async fn poll(&self) -> Result<AuthPoll, Error> {
+        match self.client.get_session(&self.token).await {
+            Ok(session) => Ok(AuthPoll::Authorized(session)),
+            Err(Error::LastFm(err)) if err.code() == LastFmErrorCode::UnauthorizedToken => {
+                Ok(AuthPoll::Pending)
+            }
+            Err(Error::LastFm(err)) if err.code() == LastFmErrorCode::TokenExpired => {
+                Err(Error::TokenExpired)
+            }
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Poll until the user authorizes the token.
+    ///
+    /// Gives up with [`Error::TokenExpired`] once the token's lifetime has run out.
+    /// Errors other than "not yet authorized" are returned immediately.
+    pub async fn wait(&self, backoff: &PollBackoff) -> Result<Session, Error> {
+        let mut delay = backoff.initial;
+
+        loop {
+            if let AuthPoll::Authorized(session) = self.poll().await? {
+                return Ok(session);
+            }
+
+            let remaining = self.expires_at().saturating_duration_since(Instant::now());
+            if remaining.is_zero() {
+                return Err(Error::TokenExpired);
+            }
+
+            debug!(?delay, "Token not authorized yet");
+            tokio::time::sleep(delay.min(remaining)).await;
+            delay = backoff.next(delay);
+        }
+    }
+}
+
+/// A temporary local HTTP listener that receives the web auth redirect.
+///
+/// # Example
+///
+/// ```no_run
+/// use soniq::auth::CallbackListener;
+/// use soniq::client::Client;
+///
+/// # async fn run(client: Client) -> Result<(), soniq::Error> {
+/// let listener = CallbackListener::bind("127.0.0.1:0").await?;
+/// let url = client.web_auth_url(Some(listener.callback_url().as_str()));
+/// println!("Open {url} to authorize");
+///
+/// let session = listener.session(&client).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct CallbackListener {
+    listener: TcpListener,
+    callback_url: Url,
+}
+
+impl CallbackListener {
+    /// Bind a listener on `addr`; use port `0` to pick a free port.
+    pub async fn bind(addr: impl ToSocketAddrs) -> Result<Self, Error> {
+        let listener = TcpListener::bind(addr).await?;
+        let callback_url = Url::parse(&format!(
+            "http://{}{}",
+            listener.local_addr()?,
+            CALLBACK_PATH
+        ))?;
+
+        Ok(Self {
+            listener,
+            callback_url,
+        })
+    }
+
+    /// The URL to pass as `cb` to [`Client::web_auth_url`].
+    pub fn callback_url(&self) -> &Url {
+        &self.callback_url
+    }
+
+    /// Wait for the redirect and return its `token` query parameter.
+    ///
+    /// Connections are served concurrently, so idle connections a browser opens ahead
+    /// of time don't block the redirect. Requests to other paths, or without a token,
+    /// are answered with `404 Not Found` and ignored. This waits indefinitely; wrap it
+    /// in `tokio::time::timeout` to bound it.
+    pub async fn token(self) -> Result<String, Error> {
+        let mut connections = JoinSet::new();
+
+        loop {
+            tokio::select! {
+                accepted = self.listener.accept() => {
+                    let (stream, _) = accepted?;
+                    connections.spawn(serve_callback(stream, self.callback_url.clone()));
+                }
+                Some(served) = connections.join_next() => {
+                    if let Ok(Some(token)) = served {
+                        return Ok(token);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Wait for the redirect and exchange its token for a session.
+    pub async fn session(self, client: &Client) -> Result<Session, Error> {
+        let token = self.token().await?;
+        client.get_session(&token).await
+    }
+}
+
+/// Answer a single connection to the [`CallbackListener`], returning the token if it
+/// carried the redirect.
+async fn serve_callback(mut stream: TcpStream, callback_url: Url) -> Option<String> {
+    let request = read_token(&mut stream, &callback_url);
+
+    match tokio::time::timeout(CALLBACK_READ_TIMEOUT, request).await {
+        Ok(Ok(Some(token))) => {
+            respond(&mut stream, "200 OK", CALLBACK_PAGE).await;
+            Some(token)
+        }
+        Ok(Ok(None)) => {
+            respond(&mut stream, "404 Not Found", "").await;
+            None
+        }
+        Ok(Err(err)) => {
+            debug!(%err, "Ignoring malformed callback request");
+            None
+        }
+        Err(_) => {
+            debug!("Closing idle callback connection");
+            None
+        }
+    }
+}
+
+/// Read a request head and extract the `token` query parameter of a callback request.
+async fn read_token(stream: &mut TcpStream, callback_url: &Url) -> Result<Option<String>, Error> {
+    let mut buf = Vec::new();
+    let mut chunk = [0u8; 1024];
+
+    while !buf.windows(4).any(|w| w == b"\r\n\r\n") {
+        let n = stream.read(&mut chunk).await?;
+        if n == 0 || buf.len() + n > MAX_CALLBACK_REQUEST {
+            return Ok(None);
+        }
+        buf.extend_from_slice(&chunk[..n]);
+    }
+
+    let head = String::from_utf8_lossy(&buf);
+    let mut request_line = head.split_whitespace();
+    let target = match (request_line.next(), request_line.next()) {
+        (Some("GET"), Some(target)) => target,
+        _ => return Ok(None),
+    };
+
+    let url = callback_url.join(target)?;
+    if url.path() != CALLBACK_PATH {
+        return Ok(None);
+    }
+
+    Ok(url
+        .query_pairs()
+        .find(|(key, value)| key == "token" && !value.is_empty())
+        .map(|(_, value)| value.into_owned()))
+}
+
+/// Write a minimal HTTP response and close the connection.
+async fn respond(stream: &mut TcpStream, status: &str, body: &str) {
+    let response = format!(
+        "HTTP/1.1 {status}\r\ncontent-type: text/html; charset=utf-8\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{body}",
+        body.len()
+    );
+    let _ = stream.write_all(response.as_bytes()).await;
+    let _ = stream.shutdown().await;
 }
@@ -1,18 +1,29 @@
 //! Client for interacting with the Last.fm API.
 
 use std::collections::BTreeMap;
+use std::future::Future;
+use std::sync::Arc;
 use std::time::Duration;
 
-use reqwest::{Client as HttpClient, Url};
+use reqwest::header::{CONTENT_TYPE, RETRY_AFTER};
+use reqwest::{Client as HttpClient, RequestBuilder, StatusCode, Url};
 use serde::de::DeserializeOwned;
-use tracing::instrument;
+use tracing::{instrument, warn};
 
-use crate::error::{Error, ErrorResponse};
+use crate::auth::Session;
+use crate::error::{Error, ErrorResponse, LastFmErrorCode};
+use crate::rate_limit::{RateLimit, RateLimiter};
+use crate::retry::{RetryPolicy, parse_retry_after};
+use crate::session::SessionStore;
 use crate::sig::create_sig;
+use crate::utils::{body_excerpt, mask_secrets, secret_fields};
 
 /// Default Last.fm API base URL.
 const LASTFM_API_BASE: &str = "https://ws.audioscrobbler.com/2.0/";
 
+/// Default Last.fm page where users approve authentication tokens.
+const LASTFM_AUTH_BASE: &str = "https://www.last.fm/api/auth/";
+
 /// Default User-Agent string.
 const DEFAULT_USER_AGENT: &str = concat!(
     "soniq/",
@@ -29,6 +40,9 @@ pub struct Client {
     api_secret: Option<String>,
     http: HttpClient,
     base_url: Url,
+    auth_base_url: Url,
+    retry: RetryPolicy,
+    rate_limiter: Option<Arc<RateLimiter>>,
 }
 
 impl Client {
@@ -52,6 +66,29 @@ impl Client {
         ClientBuilder::new(api_key)
     }
 
+    /// The API key this client was built with.
+    pub fn api_key(&self) -> &str {
+        &self.api_key
+    }
+
+    /// The Last.fm page where users approve authentication requests.
+    pub fn auth_base_url(&self) -> &Url {
+        &self.auth_base_url
+    }
+
+    /// The rate limiter shared by this client and its clones, if one was configured.
+    pub fn rate_limiter(&self) -> Option<&RateLimiter> {
+        self.rate_limiter.as_deref()
+    }
+
+    /// Binds this client to an authenticated user session.
+    ///
+    /// Fails with [`Error::MissingApiSecret`] if the client has no API secret,
+    /// since every session-authenticated call must be signed.
+    pub fn authenticate(&self, session: Session) -> Result<AuthenticatedClient, Error> {
+        AuthenticatedClient::new(self.clone(), session)
+    }
+
     /// Performs an unsigned GET request to the Last.fm API.
     #[instrument(skip(self, params))]
     pub async fn unsigned_get<T: DeserializeOwned>(
@@ -63,7 +100,7 @@ impl Client {
         params.insert("api_key".into(), self.api_key.clone());
         params.insert("format".into(), "json".into());
 
-        self.get(params).await
+        self.get(method, params).await
     }
 
     /// Performs a signed POST request without a user session key.
@@ -75,15 +112,28 @@ impl Client {
         method: &str,
         params: BTreeMap<String, String>,
     ) -> Result<T, Error> {
-        self.signed_post_with_session(method, None, params).await
+        self.send_signed(method, None, params).await
     }
 
     /// Performs a signed POST request with an optional user session key.
     ///
     /// Pass `Some(session_key)` to authenticate on behalf of a user.
     /// This requires the client to have been built with an API secret.
-    #[instrument(skip(self, session_key, params))]
+    #[deprecated(
+        note = "use `Client::authenticate` and `AuthenticatedClient::signed_post` for session calls"
+    )]
     pub async fn signed_post_with_session<T: DeserializeOwned>(
+        &self,
+        method: &str,
+        session_key: Option<&str>,
+        params: BTreeMap<String, String>,
+    ) -> Result<T, Error> {
+        self.send_signed(method, session_key, params).await
+    }
+
+    /// Signs `params` and POSTs them, adding `session_key` as `sk` if given.
+    #[instrument(skip(self, session_key, params))]
+    pub(crate) async fn send_signed<T: DeserializeOwned>(
         &self,
         method: &str,
         session_key: Option<&str>,
@@ -102,59 +152,302 @@ impl Client {
         params.insert("api_sig".into(), sig);
         params.insert("format".into(), "json".into());
 
-        self.post(params).await
+        self.post(method, params).await
     }
 
     /// Internal GET handler.
-    async fn get<T: DeserializeOwned>(&self, params: BTreeMap<String, String>) -> Result<T, Error> {
-        let res = self
-            .http
-            .get(self.base_url.clone())
-            .query(&params)
-            .send()
-            .await?;
-        Self::handle_response(res).await
+    async fn get<T: DeserializeOwned>(
+        &self,
+        method: &str,
+        params: BTreeMap<String, String>,
+    ) -> Result<T, Error> {
+        self.send_with_retry(method, &params, false, || {
+            self.http.get(self.base_url.clone()).query(&params)
+        })
+        .await
     }
 
     /// Internal POST handler.
+    ///
+    /// Signed calls either change the user's data or use up a token, so none of them
+    /// are repeated once they might have been processed.
     async fn post<T: DeserializeOwned>(
         &self,
+        method: &str,
         params: BTreeMap<String, String>,
     ) -> Result<T, Error> {
-        let res = self
-            .http
-            .post(self.base_url.clone())
-            .form(&params)
+        self.send_with_retry(method, &params, true, || {
+            self.http.post(self.base_url.clone()).form(&params)
+        })
+        .await
+    }
+
+    /// Sends the request built by `request`, retrying transient failures according
+    /// to the client's [`RetryPolicy`].
+    async fn send_with_retry<T: DeserializeOwned>(
+        &self,
+        method: &str,
+        params: &BTreeMap<String, String>,
+        write: bool,
+        request: impl Fn() -> RequestBuilder,
+    ) -> Result<T, Error> {
+        // Never let credentials end up in error messages.
+        let mut secrets = vec![self.api_key.as_str()];
+        secrets.extend(self.api_secret.as_deref());
+        secrets.extend(params.get("sk").map(String::as_str));
+
+        let mut attempt = 1;
+        loop {
+            if let Some(limiter) = &self.rate_limiter {
+                limiter.acquire().await;
+            }
+
+            let result = Self::send(request(), method, &secrets).await;
+            if let Some(limiter) = &self.rate_limiter {
+                match &result {
+                    Ok(_) => limiter.on_success(),
+                    Err((error, _)) if is_rate_limited(error) => limiter.on_rate_limited(),
+                    Err(_) => {}
+                }
+            }
+
+            let (error, retry_after) = match result {
+                Ok(value) => return Ok(value),
+                Err(failure) => failure,
+            };
+
+            if attempt >= self.retry.max_attempts || !self.retry.should_retry(&error, write) {
+                return Err(error);
+            }
+
+            let Some(delay) = self.retry.backoff(attempt, retry_after) else {
+                warn!(%error, ?retry_after, "Retry-After exceeds the maximum backoff");
+                return Err(error);
+            };
+            warn!(%error, attempt, ?delay, "Retrying Last.fm request");
+            tokio::time::sleep(delay).await;
+            attempt += 1;
+        }
+    }
+
+    /// Sends a single request. Failures carry the `Retry-After` delay, if any.
+    async fn send<T: DeserializeOwned>(
+        request: RequestBuilder,
+        method: &str,
+        secrets: &[&str],
+    ) -> Result<T, (Error, Option<Duration>)> {
+        // GET requests carry the API key in the URL, which reqwest would otherwise
+        // include in its errors.
+        let res = request
             .send()
-            .await?;
-        Self::handle_response(res).await
+            .await
+            .map_err(|err| (err.without_url().into(), None))?;
+        let retry_after = res
+            .headers()
+            .get(RETRY_AFTER)
+            .and_then(|value| value.to_str().ok())
+            .and_then(parse_retry_after);
+
+        Self::handle_response(res, method, secrets)
+            .await
+            .map_err(|err| (err, retry_after))
     }
 
     /// Handles response and deserializes or returns errors accordingly.
-    async fn handle_response<T: DeserializeOwned>(res: reqwest::Response) -> Result<T, Error> {
+    ///
+    /// Last.fm reports some errors with a 200 status, so the body is checked for an
+    /// error envelope whatever the status.
+    async fn handle_response<T: DeserializeOwned>(
+        res: reqwest::Response,
+        method: &str,
+        secrets: &[&str],
+    ) -> Result<T, Error> {
         let status = res.status();
-        let text = res.text().await?;
+        let is_html = res
+            .headers()
+            .get(CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .is_some_and(|value| value.starts_with("text/html"));
+        let text = res.text().await.map_err(reqwest::Error::without_url)?;
+        let body = text.trim_start();
+
+        if body.is_empty() {
+            return Err(Error::EmptyResponse { status });
+        }
+        if is_html || body.starts_with('<') {
+            return Err(Error::HtmlPage {
+                status,
+                title: html_title(body).map(|title| mask_secrets(&title, secrets)),
+            });
+        }
+
+        // Only bodies mentioning an `error` key can be an error envelope.
+        if (!status.is_success() || body.contains("\"error\""))
+            && let Ok(err) = serde_json::from_str::<ErrorResponse>(body)
+        {
+            return Err(Error::LastFm(err));
+        }
 
         if status.is_success() {
-            serde_json::from_str(&text).map_err(Error::from)
+            let deserializer = &mut serde_json::Deserializer::from_str(body);
+            serde_path_to_error::deserialize(deserializer).map_err(|err| {
+                let path = err.path().to_string();
+                let source = err.into_inner();
+
+                // serde may quote a session key from the body in its message.
+                let mut secrets = secrets.to_vec();
+                secrets.extend(secret_fields(body));
+                let message = mask_secrets(&source.to_string(), &secrets);
+
+                Error::Deserialize {
+                    method: method.to_string(),
+                    path,
+                    excerpt: body_excerpt(body, source.line(), source.column(), &secrets),
+                    source: serde::de::Error::custom(message),
+                }
+            })
         } else {
-            // Attempt to parse Last.fm's specific error format first.
-            match serde_json::from_str::<ErrorResponse>(&text) {
-                Ok(err) => Err(Error::LastFm(err)),
-                // If that fails, it's likely an issue with the response format itself.
-                Err(_) => Err(Error::Http { status, text }),
-            }
+            // Not Last.fm's error format; it's likely an issue with the response itself.
+            Err(Error::Http {
+                status,
+                text: mask_secrets(&text, secrets),
+            })
         }
     }
 }
 
+/// The contents of the `<title>` element of an HTML page.
+fn html_title(html: &str) -> Option<String> {
+    let lower = html.to_ascii_lowercase();
+    let start = lower.find("<title")?;
+    let start = start + lower[start..].find('>')? + 1;
+    let end = start + lower[start..].find("</title")?;
+
+    let title = html[start..end]
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ");
+    (!title.is_empty()).then_some(title)
+}
+
+/// Whether Last.fm rejected a request for exceeding the rate limit.
+fn is_rate_limited(error: &Error) -> bool {
+    match error {
+        Error::LastFm(err) => err.code() == LastFmErrorCode::RateLimitExceeded,
+        Error::Http { status, .. } => *status == StatusCode::TOO_MANY_REQUESTS,
+        _ => false,
+    }
+}
+
 impl Client {
+    /// Handler for album-related endpoints.
+    pub fn album(&self) -> crate::endpoints::album::AlbumHandler<'_> {
+        crate::endpoints::album::AlbumEndpointExt::album(self)
+    }
+
+    /// Handler for artist-related endpoints.
+    pub fn artist(&self) -> crate::endpoints::artist::ArtistHandler<'_> {
+        crate::endpoints::artist::ArtistEndpointExt::artist(self)
+    }
+
+    /// Handler for chart-related endpoints.
+    pub fn chart(&self) -> crate::endpoints::chart::ChartHandler<'_> {
+        crate::endpoints::chart::ChartEndpointExt::chart(self)
+    }
+
+    /// Handler for geo-related endpoints.
+    pub fn geo(&self) -> crate::endpoints::geo::GeoHandler<'_> {
+        crate::endpoints::geo::GeoEndpointExt::geo(self)
+    }
+
+    /// Handler for library-related endpoints.
+    pub fn library(&self) -> crate::endpoints::library::LibraryHandler<'_> {
+        crate::endpoints::library::LibraryEndpointExt::library(self)
+    }
+
+    /// Handler for tag-related endpoints.
+    pub fn tag(&self) -> crate::endpoints::tag::TagHandler<'_> {
+        crate::endpoints::tag::TagEndpointExt::tag(self)
+    }
+
+    /// Handler for track-related endpoints.
+    pub fn track(&self) -> crate::endpoints::track::TrackHandler<'_> {
+        crate::endpoints::track::TrackEndpointExt::track(self)
+    }
+
     /// Handler for user-related endpoints.
     pub fn user(&self) -> crate::endpoints::user::UserHandler<'_> {
         crate::endpoints::user::UserEndpointExt::user(self)
     }
 }
 
+/// A [`Client`] bound to an authenticated user session.
+///
+/// Methods that act on a user's account, like scrobbling, loving tracks and tagging,
+/// only exist here. Read-only methods are available through [`AuthenticatedClient::client`].
+///
+/// Use [`Client::authenticate`] to create one from a [`Session`].
+#[derive(Clone, Debug)]
+pub struct AuthenticatedClient {
+    client: Client,
+    session: Session,
+}
+
+impl AuthenticatedClient {
+    /// Binds `client` to `session`.
+    ///
+    /// Fails with [`Error::MissingApiSecret`] if the client has no API secret.
+    pub fn new(client: Client, session: Session) -> Result<Self, Error> {
+        if client.api_secret.is_none() {
+            return Err(Error::MissingApiSecret);
+        }
+
+        Ok(Self { client, session })
+    }
+
+    /// The underlying client, for read-only methods.
+    pub fn client(&self) -> &Client {
+        &self.client
+    }
+
+    /// The session this client acts on behalf of.
+    pub fn session(&self) -> &Session {
+        &self.session
+    }
+
+    /// Name of the user owning the session.
+    pub fn username(&self) -> &str {
+        &self.session.name
+    }
+
+    /// Performs a signed POST request authenticated with the session key.
+    #[instrument(skip(self, params))]
+    pub async fn signed_post<T: DeserializeOwned>(
+        &self,
+        method: &str,
+        params: BTreeMap<String, String>,
+    ) -> Result<T, Error> {
+        self.client
+            .send_signed(method, Some(&self.session.key), params)
+            .await
+    }
+
+    /// Handler for album-related write endpoints.
+    pub fn album(&self) -> crate::endpoints::album::AuthAlbumHandler<'_> {
+        crate::endpoints::album::AuthAlbumEndpointExt::album(self)
+    }
+
+    /// Handler for artist-related write endpoints.
+    pub fn artist(&self) -> crate::endpoints::artist::AuthArtistHandler<'_> {
+        crate::endpoints::artist::AuthArtistEndpointExt::artist(self)
+    }
+
+    /// Handler for track-related write endpoints.
+    pub fn track(&self) -> crate::endpoints::track::AuthTrackHandler<'_> {
+        crate::endpoints::track::AuthTrackEndpointExt::track(self)
+    }
+}
+
 /// Builder for [`Client`].
 #[derive(Debug)]
 pub struct ClientBuilder {
@@ -163,6 +456,9 @@ pub struct ClientBuilder {
     timeout: Duration,
     user_agent: String,
     base_url: Url,
+    auth_base_url: Url,
+    retry: RetryPolicy,
+    rate_limit: Option<RateLimit>,
 }
 
 impl ClientBuilder {
@@ -174,6 +470,9 @@ impl ClientBuilder {
             timeout: Duration::from_secs(10),
             user_agent: DEFAULT_USER_AGENT.to_string(),
             base_url: Url::parse(LASTFM_API_BASE).expect("Default base URL is invalid?"),
+            auth_base_url: Url::parse(LASTFM_AUTH_BASE).expect("Default auth URL is invalid?"),
+            retry: RetryPolicy::none(),
+            rate_limit: None,
         }
     }
 
@@ -196,11 +495,31 @@ impl ClientBuilder {
     // }
 
     /// Overrides the Last.fm API base URL (e.g., for testing or proxies).
+    #[allow(clippy::empty_line_after_doc_comments)]
     pub fn base_url(mut self, url: impl AsRef<str>) -> Result<Self, url::ParseError> {
         self.base_url = Url::parse(url.as_ref())?;
         Ok(self)
     }
 
+    /// Sets how transient failures are retried. By default, nothing is retried.
+    pub fn retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry = policy;
+        self
+    }
+
+    /// Limits how fast requests are sent, e.g. `RateLimit::default()` for Last.fm's
+    /// five requests per second. The limiter is shared by all clones of the client.
+    pub fn rate_limit(mut self, limit: RateLimit) -> Self {
+        self.rate_limit = Some(limit);
+        self
+    }
+
+    /// Overrides the page users are sent to for approving authentication requests.
+    pub fn auth_base_url(mut self, url: impl AsRef<str>) -> Result<Self, url::ParseError> {
+        self.auth_base_url = Url::parse(url.as_ref())?;
+        Ok(self)
+    }
+
     /// Builds the `Client`.
     pub fn build(self) -> Result<Client, Error> {
         let http = HttpClient::builder()
@@ -213,6 +532,63 @@ impl ClientBuilder {
             api_secret: self.api_secret,
             http,
             base_url: self.base_url,
+            auth_base_url: self.auth_base_url,
+            retry: self.retry,
+            rate_limiter: self
+                .rate_limit
+                .map(|limit| Arc::new(RateLimiter::new(limit))),
         })
     }
+
+    /// Builds an [`AuthenticatedClient`] from the session in `store`.
+    ///
+    /// If the store is empty, `authorize` runs the auth flow with the built client,
+    /// and the resulting session is saved to `store` before it is used.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use soniq::auth::PollBackoff;
+    /// use soniq::client::Client;
+    /// use soniq::session::FileSessionStore;
+    ///
+    /// # async fn run() -> Result<(), soniq::Error> {
+    /// let store = FileSessionStore::new("lastfm-session.json");
+    /// let client = Client::builder("YOUR_API_KEY")
+    ///     .api_secret("YOUR_API_SECRET")
+    ///     .build_with_session(&store, |client| async move {
+    ///         let auth = client.desktop_auth().await?;
+    ///         println!("Open {} to authorize", auth.url());
+    ///         auth.wait(&PollBackoff::default()).await
+    ///     })
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn build_with_session<S, F, Fut>(
+        self,
+        store: &S,
+        authorize: F,
+    ) -> Result<AuthenticatedClient, Error>
+    where
+        S: SessionStore,
+        F: FnOnce(Client) -> Fut,
+        Fut: Future<Output = Result<Session, Error>>,
+    {
+        if self.api_secret.is_none() {
+            return Err(Error::MissingApiSecret);
+        }
+        let client = self.build()?;
+
+        let session = match store.load().await? {
+            Some(session) => session,
+            None => {
+                let session = authorize(client.clone()).await?;
+                store.save(&session).await?;
+                session
+            }
+        };
+
+        client.authenticate(session)
+    }
 }
@@ -0,0 +1,211 @@
+//! Album API methods for Last.fm.
+
+use std::collections::BTreeMap;
+
+use serde::de::IgnoredAny;
+
+use crate::{
+    client::{AuthenticatedClient, Client},
+    endpoints::common::{insert_autocorrect, insert_opt, join_tags},
+    error::Error,
+    models::{
+        album::{
+            AlbumGetInfoResponse, AlbumGetTagsResponse, AlbumGetTopTagsResponse, AlbumInfo,
+            AlbumSearchResponse, AlbumSearchResults,
+        },
+        common::{Tag, TopTags},
+    },
+};
+
+/// Identifies an album either by artist and album name or by MusicBrainz ID.
+///
+/// `(artist, album)` tuples convert into [`AlbumLookup::Name`].
+#[derive(Debug, Clone)]
+pub enum AlbumLookup {
+    Name { artist: String, album: String },
+    Mbid(String),
+}
+
+impl AlbumLookup {
+    /// Looks the album up by artist and album name.
+    pub fn new(artist: impl Into<String>, album: impl Into<String>) -> Self {
+        Self::Name {
+            artist: artist.into(),
+            album: album.into(),
+        }
+    }
+
+    /// Looks the album up by MusicBrainz ID.
+    pub fn mbid(mbid: impl Into<String>) -> Self {
+        Self::Mbid(mbid.into())
+    }
+
+    fn append_params(&self, params: &mut BTreeMap<String, String>) {
+        match self {
+            Self::Name { artist, album } => {
+                params.insert("artist".into(), artist.clone());
+                params.insert("album".into(), album.clone());
+            }
+            Self::Mbid(mbid) => {
+                params.insert("mbid".into(), mbid.clone());
+            }
+        }
+    }
+}
+
+impl<A: Into<String>, B: Into<String>> From<(A, B)> for AlbumLookup {
+    fn from((artist, album): (A, B)) -> Self {
+        Self::new(artist, album)
+    }
+}
+
+/// Extension trait that provides album-related API methods.
+pub trait AlbumEndpointExt {
+    fn album(&self) -> AlbumHandler<'_>;
+}
+
+/// Implements `album()` on the client.
+impl AlbumEndpointExt for Client {
+    fn album(&self) -> AlbumHandler<'_> {
+        AlbumHandler { client: self }
+    }
+}
+
+/// Handles `album.*` Last.fm API methods.
+#[derive(Debug)]
+pub struct AlbumHandler<'a> {
+    pub(crate) client: &'a Client,
+}
+
+impl<'a> AlbumHandler<'a> {
+    /// Get the metadata and tracklist for an album.
+    ///
+    /// `lang` selects the wiki language (ISO 639 alpha-2). With a `username`,
+    /// the response includes that user's playcount for the album.
+    ///
+    /// [API Reference](https://www.last.fm/api/show/album.getInfo)
+    pub async fn get_info(
+        &self,
+        album: impl Into<AlbumLookup>,
+        autocorrect: bool,
+        lang: Option<&str>,
+        username: Option<&str>,
+    ) -> Result<AlbumInfo, Error> {
+        let mut params = BTreeMap::new();
+        album.into().append_params(&mut params);
+        insert_autocorrect(&mut params, autocorrect);
+        insert_opt(&mut params, "lang", lang);
+        insert_opt(&mut params, "username", username);
+
+        let response: AlbumGetInfoResponse =
+            self.client.unsigned_get("album.getInfo", params).await?;
+
+        Ok(response.album)
+    }
+
+    /// Get the tags `username` applied to an album.
+    ///
+    /// [API Reference](https://www.last.fm/api/show/album.getTags)
+    pub async fn get_tags(
+        &self,
+        album: impl Into<AlbumLookup>,
+        autocorrect: bool,
+        username: &str,
+    ) -> Result<Vec<Tag>, Error> {
+        let mut params = BTreeMap::new();
+        album.into().append_params(&mut params);
+        insert_autocorrect(&mut params, autocorrect);
+        params.insert("user".into(), username.to_string());
+
+        let response: AlbumGetTagsResponse =
+            self.client.unsigned_get("album.getTags", params).await?;
+
+        Ok(response.tags.tag)
+    }
+
+    /// Get the top tags for an album, ordered by popularity.
+    ///
+    /// [API Reference](https://www.last.fm/api/show/album.getTopTags)
+    pub async fn get_top_tags(
+        &self,
+        album: impl Into<AlbumLookup>,
+        autocorrect: bool,
+    ) -> Result<TopTags, Error> {
+        let mut params = BTreeMap::new();
+        album.into().append_params(&mut params);
+        insert_autocorrect(&mut params, autocorrect);
+
+        let response: AlbumGetTopTagsResponse =
+            self.client.unsigned_get("album.getTopTags", params).await?;
+
+        Ok(response.toptags)
+    }
+
+    /// Search for an album by name, ordered by relevance.
+    ///
+    /// [API Reference](https://www.last.fm/api/show/album.search)
+    pub async fn search(
+        &self,
+        album: &str,
+        limit: Option<u32>,
+        page: Option<u32>,
+    ) -> Result<AlbumSearchResults, Error> {
+        let mut params = BTreeMap::new();
+        params.insert("album".into(), album.to_string());
+        insert_opt(&mut params, "limit", limit);
+        insert_opt(&mut params, "page", page);
+
+        let response: AlbumSearchResponse =
+            self.client.unsigned_get("album.search", params).await?;
+
+        Ok(response.results)
+    }
+}
+
+/// Extension trait that provides album-related write methods.
+pub trait AuthAlbumEndpointExt {
+    fn album(&self) -> AuthAlbumHandler<'_>;
+}
+
+/// Implements `album()` on the authenticated client.
+impl AuthAlbumEndpointExt for AuthenticatedClient {
+    fn album(&self) -> AuthAlbumHandler<'_> {
+        AuthAlbumHandler { client: self }
+    }
+}
+
+/// Handles `album.*` Last.fm API methods that act on the session user's account.
+#[derive(Debug)]
+pub struct AuthAlbumHandler<'a> {
+    pub(crate) client: &'a AuthenticatedClient,
+}
+
+impl<'a> AuthAlbumHandler<'a> {
+    /// Tag an album with up to 10 user-supplied tags.
+    ///
+    /// [API Reference](https://www.last.fm/api/show/album.addTags)
+    pub async fn add_tags(&self, artist: &str, album: &str, tags: &[&str]) -> Result<(), Error> {
+        let mut params = BTreeMap::new();
+        params.insert("artist".into(), artist.to_string());
+        params.insert("album".into(), album.to_string());
+        params.insert("tags".into(), join_tags(tags)?);
+
+        let _: IgnoredAny = self.client.signed_post("album.addTags", params).await?;
+
+        Ok(())
+    }
+
+    /// Remove one of the user's tags from an album.
+    ///
+    /// [API Reference](https://www.last.fm/api/show/album.removeTag)
+    pub async fn remove_tag(&self, artist: &str, album: &str, tag: &str) -> Result<(), Error> {
+        let mut params = BTreeMap::new();
+        params.insert("artist".into(), artist.to_string());
+        params.insert("album".into(), album.to_string());
+        params.insert("tag".into(), tag.to_string());
+
+        let _: IgnoredAny = self.client.signed_post("album.removeTag", params).await?;
+
+        Ok(())
+    }
+}
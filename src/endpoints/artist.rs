@@ -0,0 +1,293 @@
+//! Artist API methods for Last.fm.
+
+use std::collections::BTreeMap;
+
+use serde::de::IgnoredAny;
+
+use crate::{
+    client::{AuthenticatedClient, Client},
+    endpoints::common::{insert_autocorrect, insert_opt, join_tags},
+    error::Error,
+    models::{
+        artist::{
+            ArtistCorrection, ArtistGetCorrectionResponse, ArtistGetInfoResponse,
+            ArtistGetSimilarResponse, ArtistGetTagsResponse, ArtistGetTopAlbumsResponse,
+            ArtistGetTopTagsResponse, ArtistGetTopTracksResponse, ArtistInfo, ArtistSearchResponse,
+            ArtistSearchResults, ArtistTopAlbums, ArtistTopTags, ArtistTopTracks, SimilarArtists,
+        },
+        common::Tag,
+    },
+};
+
+/// Identifies an artist either by name or by MusicBrainz ID.
+///
+/// Plain strings convert into [`ArtistLookup::Name`].
+#[derive(Debug, Clone)]
+pub enum ArtistLookup {
+    Name(String),
+    Mbid(String),
+}
+
+impl ArtistLookup {
+    /// Looks the artist up by MusicBrainz ID.
+    pub fn mbid(mbid: impl Into<String>) -> Self {
+        Self::Mbid(mbid.into())
+    }
+
+    fn append_params(&self, params: &mut BTreeMap<String, String>) {
+        match self {
+            Self::Name(name) => params.insert("artist".into(), name.clone()),
+            Self::Mbid(mbid) => params.insert("mbid".into(), mbid.clone()),
+        };
+    }
+}
+
+impl From<&str> for ArtistLookup {
+    fn from(name: &str) -> Self {
+        Self::Name(name.to_string())
+    }
+}
+
+impl From<String> for ArtistLookup {
+    fn from(name: String) -> Self {
+        Self::Name(name)
+    }
+}
+
+/// Extension trait that provides artist-related API methods.
+pub trait ArtistEndpointExt {
+    fn artist(&self) -> ArtistHandler<'_>;
+}
+
+/// Implements `artist()` on the client.
+impl ArtistEndpointExt for Client {
+    fn artist(&self) -> ArtistHandler<'_> {
+        ArtistHandler { client: self }
+    }
+}
+
+/// Handles `artist.*` Last.fm API methods.
+#[derive(Debug)]
+pub struct ArtistHandler<'a> {
+    pub(crate) client: &'a Client,
+}
+
+impl<'a> ArtistHandler<'a> {
+    /// Get the metadata for an artist, including their biography.
+    ///
+    /// `lang` selects the biography language (ISO 639 alpha-2). With a `username`,
+    /// the stats include that user's playcount.
+    ///
+    /// [API Reference](https://www.last.fm/api/show/artist.getInfo)
+    pub async fn get_info(
+        &self,
+        artist: impl Into<ArtistLookup>,
+        autocorrect: bool,
+        lang: Option<&str>,
+        username: Option<&str>,
+    ) -> Result<ArtistInfo, Error> {
+        let mut params = BTreeMap::new();
+        artist.into().append_params(&mut params);
+        insert_autocorrect(&mut params, autocorrect);
+        insert_opt(&mut params, "lang", lang);
+        insert_opt(&mut params, "username", username);
+
+        let response: ArtistGetInfoResponse =
+            self.client.unsigned_get("artist.getInfo", params).await?;
+
+        Ok(response.artist)
+    }
+
+    /// Check whether an artist name has a correction to a canonical artist.
+    ///
+    /// Returns `None` if Last.fm has no correction for the name.
+    ///
+    /// [API Reference](https://www.last.fm/api/show/artist.getCorrection)
+    pub async fn get_correction(&self, artist: &str) -> Result<Option<ArtistCorrection>, Error> {
+        let mut params = BTreeMap::new();
+        params.insert("artist".into(), artist.to_string());
+
+        let response: ArtistGetCorrectionResponse = self
+            .client
+            .unsigned_get("artist.getCorrection", params)
+            .await?;
+
+        Ok(response.corrections.map(|c| c.correction))
+    }
+
+    /// Get all the artists similar to this artist.
+    ///
+    /// [API Reference](https://www.last.fm/api/show/artist.getSimilar)
+    pub async fn get_similar(
+        &self,
+        artist: impl Into<ArtistLookup>,
+        autocorrect: bool,
+        limit: Option<u32>,
+    ) -> Result<SimilarArtists, Error> {
+        let mut params = BTreeMap::new();
+        artist.into().append_params(&mut params);
+        insert_autocorrect(&mut params, autocorrect);
+        insert_opt(&mut params, "limit", limit);
+
+        let response: ArtistGetSimilarResponse = self
+            .client
+            .unsigned_get("artist.getSimilar", params)
+            .await?;
+
+        Ok(response.similarartists)
+    }
+
+    /// Get the tags `username` applied to an artist.
+    ///
+    /// [API Reference](https://www.last.fm/api/show/artist.getTags)
+    pub async fn get_tags(
+        &self,
+        artist: impl Into<ArtistLookup>,
+        autocorrect: bool,
+        username: &str,
+    ) -> Result<Vec<Tag>, Error> {
+        let mut params = BTreeMap::new();
+        artist.into().append_params(&mut params);
+        insert_autocorrect(&mut params, autocorrect);
+        params.insert("user".into(), username.to_string());
+
+        let response: ArtistGetTagsResponse =
+            self.client.unsigned_get("artist.getTags", params).await?;
+
+        Ok(response.tags.tag)
+    }
+
+    /// Get the top albums for an artist, ordered by popularity.
+    ///
+    /// [API Reference](https://www.last.fm/api/show/artist.getTopAlbums)
+    pub async fn get_top_albums(
+        &self,
+        artist: impl Into<ArtistLookup>,
+        autocorrect: bool,
+        limit: Option<u32>,
+        page: Option<u32>,
+    ) -> Result<ArtistTopAlbums, Error> {
+        let mut params = BTreeMap::new();
+        artist.into().append_params(&mut params);
+        insert_autocorrect(&mut params, autocorrect);
+        insert_opt(&mut params, "limit", limit);
+        insert_opt(&mut params, "page", page);
+
+        let response: ArtistGetTopAlbumsResponse = self
+            .client
+            .unsigned_get("artist.getTopAlbums", params)
+            .await?;
+
+        Ok(response.topalbums)
+    }
+
+    /// Get the top tags for an artist, ordered by popularity.
+    ///
+    /// [API Reference](https://www.last.fm/api/show/artist.getTopTags)
+    pub async fn get_top_tags(
+        &self,
+        artist: impl Into<ArtistLookup>,
+        autocorrect: bool,
+    ) -> Result<ArtistTopTags, Error> {
+        let mut params = BTreeMap::new();
+        artist.into().append_params(&mut params);
+        insert_autocorrect(&mut params, autocorrect);
+
+        let response: ArtistGetTopTagsResponse = self
+            .client
+            .unsigned_get("artist.getTopTags", params)
+            .await?;
+
+        Ok(response.toptags)
+    }
+
+    /// Get the top tracks by an artist, ordered by popularity.
+    ///
+    /// [API Reference](https://www.last.fm/api/show/artist.getTopTracks)
+    pub async fn get_top_tracks(
+        &self,
+        artist: impl Into<ArtistLookup>,
+        autocorrect: bool,
+        limit: Option<u32>,
+        page: Option<u32>,
+    ) -> Result<ArtistTopTracks, Error> {
+        let mut params = BTreeMap::new();
+        artist.into().append_params(&mut params);
+        insert_autocorrect(&mut params, autocorrect);
+        insert_opt(&mut params, "limit", limit);
+        insert_opt(&mut params, "page", page);
+
+        let response: ArtistGetTopTracksResponse = self
+            .client
+            .unsigned_get("artist.getTopTracks", params)
+            .await?;
+
+        Ok(response.toptracks)
+    }
+
+    /// Search for an artist by name, ordered by relevance.
+    ///
+    /// [API Reference](https://www.last.fm/api/show/artist.search)
+    pub async fn search(
+        &self,
+        artist: &str,
+        limit: Option<u32>,
+        page: Option<u32>,
+    ) -> Result<ArtistSearchResults, Error> {
+        let mut params = BTreeMap::new();
+        params.insert("artist".into(), artist.to_string());
+        insert_opt(&mut params, "limit", limit);
+        insert_opt(&mut params, "page", page);
+
+        let response: ArtistSearchResponse =
+            self.client.unsigned_get("artist.search", params).await?;
+
+        Ok(response.results)
+    }
+}
+
+/// Extension trait that provides artist-related write methods.
+pub trait AuthArtistEndpointExt {
+    fn artist(&self) -> AuthArtistHandler<'_>;
+}
+
+/// Implements `artist()` on the authenticated client.
+impl AuthArtistEndpointExt for AuthenticatedClient {
+    fn artist(&self) -> AuthArtistHandler<'_> {
+        AuthArtistHandler { client: self }
+    }
+}
+
+/// Handles `artist.*` Last.fm API methods that act on the session user's account.
+#[derive(Debug)]
+pub struct AuthArtistHandler<'a> {
+    pub(crate) client: &'a AuthenticatedClient,
+}
+
+impl<'a> AuthArtistHandler<'a> {
+    /// Tag an artist with up to 10 user-supplied tags.
+    ///
+    /// [API Reference](https://www.last.fm/api/show/artist.addTags)
+    pub async fn add_tags(&self, artist: &str, tags: &[&str]) -> Result<(), Error> {
+        let mut params = BTreeMap::new();
+        params.insert("artist".into(), artist.to_string());
+        params.insert("tags".into(), join_tags(tags)?);
+
+        let _: IgnoredAny = self.client.signed_post("artist.addTags", params).await?;
+
+        Ok(())
+    }
+
+    /// Remove one of the user's tags from an artist.
+    ///
+    /// [API Reference](https://www.last.fm/api/show/artist.removeTag)
+    pub async fn remove_tag(&self, artist: &str, tag: &str) -> Result<(), Error> {
+        let mut params = BTreeMap::new();
+        params.insert("artist".into(), artist.to_string());
+        params.insert("tag".into(), tag.to_string());
+
+        let _: IgnoredAny = self.client.signed_post("artist.removeTag", params).await?;
+
+        Ok(())
+    }
+}
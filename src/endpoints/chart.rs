@@ -0,0 +1,88 @@
+//! Chart API methods for Last.fm.
+
+use std::collections::BTreeMap;
+
+use crate::{
+    client::Client,
+    endpoints::common::insert_opt,
+    error::Error,
+    models::chart::{
+        ChartGetTopArtistsResponse, ChartGetTopTagsResponse, ChartGetTopTracksResponse,
+        ChartTopArtists, ChartTopTags, ChartTopTracks,
+    },
+};
+
+/// Extension trait that provides chart-related API methods.
+pub trait ChartEndpointExt {
+    fn chart(&self) -> ChartHandler<'_>;
+}
+
+/// Implements `chart()` on the client.
+impl ChartEndpointExt for Client {
+    fn chart(&self) -> ChartHandler<'_> {
+        ChartHandler { client: self }
+    }
+}
+
+/// Handles `chart.*` Last.fm API methods.
+#[derive(Debug)]
+pub struct ChartHandler<'a> {
+    pub(crate) client: &'a Client,
+}
+
+impl<'a> ChartHandler<'a> {
+    /// Get the top artists chart.
+    ///
+    /// [API Reference](https://www.last.fm/api/show/chart.getTopArtists)
+    pub async fn get_top_artists(
+        &self,
+        limit: Option<u32>,
+        page: Option<u32>,
+    ) -> Result<ChartTopArtists, Error> {
+        let response: ChartGetTopArtistsResponse = self
+            .client
+            .unsigned_get("chart.getTopArtists", page_params(limit, page))
+            .await?;
+
+        Ok(response.artists)
+    }
+
+    /// Get the top tags chart.
+    ///
+    /// [API Reference](https://www.last.fm/api/show/chart.getTopTags)
+    pub async fn get_top_tags(
+        &self,
+        limit: Option<u32>,
+        page: Option<u32>,
+    ) -> Result<ChartTopTags, Error> {
+        let response: ChartGetTopTagsResponse = self
+            .client
+            .unsigned_get("chart.getTopTags", page_params(limit, page))
+            .await?;
+
+        Ok(response.tags)
+    }
+
+    /// Get the top tracks chart.
+    ///
+    /// [API Reference](https://www.last.fm/api/show/chart.getTopTracks)
+    pub async fn get_top_tracks(
+        &self,
+        limit: Option<u32>,
+        page: Option<u32>,
+    ) -> Result<ChartTopTracks, Error> {
+        let response: ChartGetTopTracksResponse = self
+            .client
+            .unsigned_get("chart.getTopTracks", page_params(limit, page))
+            .await?;
+
+        Ok(response.tracks)
+    }
+}
+
+fn page_params(limit: Option<u32>, page: Option<u32>) -> BTreeMap<String, String> {
+    let mut params = BTreeMap::new();
+    insert_opt(&mut params, "limit", limit);
+    insert_opt(&mut params, "page", page);
+    params
+}
@@ -0,0 +1,38 @@
+//! Helpers shared by the endpoint modules for building request parameters.
+
+use std::collections::BTreeMap;
+
+use crate::error::Error;
+
+/// Maximum number of tags accepted by a single `*.addTags` call.
+pub const MAX_TAGS: usize = 10;
+
+/// Inserts `value` under `key` if it is set.
+pub(crate) fn insert_opt<V: ToString>(
+    params: &mut BTreeMap<String, String>,
+    key: &str,
+    value: Option<V>,
+) {
+    if let Some(value) = value {
+        params.insert(key.into(), value.to_string());
+    }
+}
+
+/// Inserts the `autocorrect` flag. Last.fm defaults to `0`, so it's only sent when enabled.
+pub(crate) fn insert_autocorrect(params: &mut BTreeMap<String, String>, autocorrect: bool) {
+    if autocorrect {
+        params.insert("autocorrect".into(), "1".into());
+    }
+}
+
+/// Joins tags into the comma-separated form used by `*.addTags`.
+pub(crate) fn join_tags(tags: &[&str]) -> Result<String, Error> {
+    if tags.is_empty() || tags.len() > MAX_TAGS {
+        return Err(Error::InvalidArgument(format!(
+            "between 1 and {MAX_TAGS} tags are required, got {}",
+            tags.len()
+        )));
+    }
+
+    Ok(tags.join(","))
+}
@@ -0,0 +1,79 @@
+//! Geo API methods for Last.fm.
+
+use std::collections::BTreeMap;
+
+use crate::{
+    client::Client,
+    endpoints::common::insert_opt,
+    error::Error,
+    models::geo::{GeoGetTopArtistsResponse, GeoGetTopTracksResponse, GeoTopArtists, GeoTopTracks},
+};
+
+/// Extension trait that provides geo-related API methods.
+pub trait GeoEndpointExt {
+    fn geo(&self) -> GeoHandler<'_>;
+}
+
+/// Implements `geo()` on the client.
+impl GeoEndpointExt for Client {
+    fn geo(&self) -> GeoHandler<'_> {
+        GeoHandler { client: self }
+    }
+}
+
+/// Handles `geo.*` Last.fm API methods.
+#[derive(Debug)]
+pub struct GeoHandler<'a> {
+    pub(crate) client: &'a Client,
+}
+
+impl<'a> GeoHandler<'a> {
+    /// Get the most popular artists in a country.
+    ///
+    /// `country` is a country name as defined by ISO 3166-1, e.g. `"Spain"`.
+    ///
+    /// [API Reference](https://www.last.fm/api/show/geo.getTopArtists)
+    pub async fn get_top_artists(
+        &self,
+        country: &str,
+        limit: Option<u32>,
+        page: Option<u32>,
+    ) -> Result<GeoTopArtists, Error> {
+        let mut params = BTreeMap::new();
+        params.insert("country".into(), country.to_string());
+        insert_opt(&mut params, "limit", limit);
+        insert_opt(&mut params, "page", page);
+
+        let response: GeoGetTopArtistsResponse = self
+            .client
+            .unsigned_get("geo.getTopArtists", params)
+            .await?;
+
+        Ok(response.topartists)
+    }
+
+    /// Get the most popular tracks in a country.
+    ///
+    /// `country` is a country name as defined by ISO 3166-1, e.g. `"Spain"`.
+    /// `location` narrows the chart to a metro within that country.
+    ///
+    /// [API Reference](https://www.last.fm/api/show/geo.getTopTracks)
+    pub async fn get_top_tracks(
+        &self,
+        country: &str,
+        location: Option<&str>,
+        limit: Option<u32>,
+        page: Option<u32>,
+    ) -> Result<GeoTopTracks, Error> {
+        let mut params = BTreeMap::new();
+        params.insert("country".into(), country.to_string());
+        insert_opt(&mut params, "location", location);
+        insert_opt(&mut params, "limit", limit);
+        insert_opt(&mut params, "page", page);
+
+        let response: GeoGetTopTracksResponse =
+            self.client.unsigned_get("geo.getTopTracks", params).await?;
+
+        Ok(response.tracks)
+    }
+}
@@ -0,0 +1,52 @@
+//! Library API methods for Last.fm.
+
+use std::collections::BTreeMap;
+
+use crate::{
+    client::Client,
+    endpoints::common::insert_opt,
+    error::Error,
+    models::library::{LibraryArtists, LibraryGetArtistsResponse},
+};
+
+/// Extension trait that provides library-related API methods.
+pub trait LibraryEndpointExt {
+    fn library(&self) -> LibraryHandler<'_>;
+}
+
+/// Implements `library()` on the client.
+impl LibraryEndpointExt for Client {
+    fn library(&self) -> LibraryHandler<'_> {
+        LibraryHandler { client: self }
+    }
+}
+
+/// Handles `library.*` Last.fm API methods.
+#[derive(Debug)]
+pub struct LibraryHandler<'a> {
+    pub(crate) client: &'a Client,
+}
+
+impl<'a> LibraryHandler<'a> {
+    /// Get the artists in a user's library, with play and tag counts.
+    ///
+    /// [API Reference](https://www.last.fm/api/show/library.getArtists)
+    pub async fn get_artists(
+        &self,
+        username: &str,
+        limit: Option<u32>,
+        page: Option<u32>,
+    ) -> Result<LibraryArtists, Error> {
+        let mut params = BTreeMap::new();
+        params.insert("user".into(), username.to_string());
+        insert_opt(&mut params, "limit", limit);
+        insert_opt(&mut params, "page", page);
+
+        let response: LibraryGetArtistsResponse = self
+            .client
+            .unsigned_get("library.getArtists", params)
+            .await?;
+
+        Ok(response.artists)
+    }
+}
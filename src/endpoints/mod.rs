@@ -1,3 +1,11 @@
 //! Contains all Last.fm API endpoint modules.
 
+pub mod album;
+pub mod artist;
+pub mod chart;
+pub mod common;
+pub mod geo;
+pub mod library;
+pub mod tag;
+pub mod track;
 pub mod user;
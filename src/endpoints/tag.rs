@@ -0,0 +1,153 @@
+//! Tag API methods for Last.fm.
+
+use std::collections::BTreeMap;
+
+use crate::{
+    client::Client,
+    endpoints::common::insert_opt,
+    error::Error,
+    models::{
+        common::{ChartRange, Tag},
+        tag::{
+            TagGetInfoResponse, TagGetSimilarResponse, TagGetTopAlbumsResponse,
+            TagGetTopArtistsResponse, TagGetTopTagsResponse, TagGetTopTracksResponse,
+            TagGetWeeklyChartListResponse, TagInfo, TagTopAlbums, TagTopArtists, TagTopTags,
+            TagTopTracks,
+        },
+    },
+};
+
+/// Extension trait that provides tag-related API methods.
+pub trait TagEndpointExt {
+    fn tag(&self) -> TagHandler<'_>;
+}
+
+/// Implements `tag()` on the client.
+impl TagEndpointExt for Client {
+    fn tag(&self) -> TagHandler<'_> {
+        TagHandler { client: self }
+    }
+}
+
+/// Handles `tag.*` Last.fm API methods.
+#[derive(Debug)]
+pub struct TagHandler<'a> {
+    pub(crate) client: &'a Client,
+}
+
+impl<'a> TagHandler<'a> {
+    /// Get the metadata for a tag, including its wiki.
+    ///
+    /// `lang` selects the wiki language (ISO 639 alpha-2).
+    ///
+    /// [API Reference](https://www.last.fm/api/show/tag.getInfo)
+    pub async fn get_info(&self, tag: &str, lang: Option<&str>) -> Result<TagInfo, Error> {
+        let mut params = tag_params(tag);
+        insert_opt(&mut params, "lang", lang);
+
+        let response: TagGetInfoResponse = self.client.unsigned_get("tag.getInfo", params).await?;
+
+        Ok(response.tag)
+    }
+
+    /// Search for tags similar to this one, based on listening data.
+    ///
+    /// [API Reference](https://www.last.fm/api/show/tag.getSimilar)
+    pub async fn get_similar(&self, tag: &str) -> Result<Vec<Tag>, Error> {
+        let response: TagGetSimilarResponse = self
+            .client
+            .unsigned_get("tag.getSimilar", tag_params(tag))
+            .await?;
+
+        Ok(response.similartags.tag)
+    }
+
+    /// Get the top albums tagged by this tag, ordered by tag count.
+    ///
+    /// [API Reference](https://www.last.fm/api/show/tag.getTopAlbums)
+    pub async fn get_top_albums(
+        &self,
+        tag: &str,
+        limit: Option<u32>,
+        page: Option<u32>,
+    ) -> Result<TagTopAlbums, Error> {
+        let mut params = tag_params(tag);
+        insert_opt(&mut params, "limit", limit);
+        insert_opt(&mut params, "page", page);
+
+        let response: TagGetTopAlbumsResponse =
+            self.client.unsigned_get("tag.getTopAlbums", params).await?;
+
+        Ok(response.albums)
+    }
+
+    /// Get the top artists tagged by this tag, ordered by tag count.
+    ///
+    /// [API Reference](https://www.last.fm/api/show/tag.getTopArtists)
+    pub async fn get_top_artists(
+        &self,
+        tag: &str,
+        limit: Option<u32>,
+        page: Option<u32>,
+    ) -> Result<TagTopArtists, Error> {
+        let mut params = tag_params(tag);
+        insert_opt(&mut params, "limit", limit);
+        insert_opt(&mut params, "page", page);
+
+        let response: TagGetTopArtistsResponse = self
+            .client
+            .unsigned_get("tag.getTopArtists", params)
+            .await?;
+
+        Ok(response.topartists)
+    }
+
+    /// Get the most used tags across Last.fm, ordered by popularity.
+    ///
+    /// [API Reference](https://www.last.fm/api/show/tag.getTopTags)
+    pub async fn get_top_tags(&self) -> Result<TagTopTags, Error> {
+        let response: TagGetTopTagsResponse = self
+            .client
+            .unsigned_get("tag.getTopTags", BTreeMap::new())
+            .await?;
+
+        Ok(response.toptags)
+    }
+
+    /// Get the top tracks tagged by this tag, ordered by tag count.
+    ///
+    /// [API Reference](https://www.last.fm/api/show/tag.getTopTracks)
+    pub async fn get_top_tracks(
+        &self,
+        tag: &str,
+        limit: Option<u32>,
+        page: Option<u32>,
+    ) -> Result<TagTopTracks, Error> {
+        let mut params = tag_params(tag);
+        insert_opt(&mut params, "limit", limit);
+        insert_opt(&mut params, "page", page);
+
+        let response: TagGetTopTracksResponse =
+            self.client.unsigned_get("tag.getTopTracks", params).await?;
+
+        Ok(response.tracks)
+    }
+
+    /// Get the date ranges of the weekly charts available for this tag.
+    ///
+    /// [API Reference](https://www.last.fm/api/show/tag.getWeeklyChartList)
+    pub async fn get_weekly_chart_list(&self, tag: &str) -> Result<Vec<ChartRange>, Error> {
+        let response: TagGetWeeklyChartListResponse = self
+            .client
+            .unsigned_get("tag.getWeeklyChartList", tag_params(tag))
+            .await?;
+
+        Ok(response.weeklychartlist.chart)
+    }
+}
+
+fn tag_params(tag: &str) -> BTreeMap<String, String> {
+    let mut params = BTreeMap::new();
+    params.insert("tag".into(), tag.to_string());
+    params
+}
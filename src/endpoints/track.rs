@@ -0,0 +1,519 @@
+//! Track API methods for Last.fm.
+
+use std::collections::{BTreeMap, HashSet};
+
+use chrono::{DateTime, Utc};
+use serde::de::IgnoredAny;
+
+use crate::{
+    client::{AuthenticatedClient, Client},
+    endpoints::common::{insert_autocorrect, insert_opt},
+    error::Error,
+    models::{
+        common::{Tag, TopTags},
+        track::{
+            NowPlayingResult, Scrobbles, SimilarTracks, TrackCorrection,
+            TrackGetCorrectionResponse, TrackGetInfoResponse, TrackGetSimilarResponse,
+            TrackGetTagsResponse, TrackGetTopTagsResponse, TrackInfo, TrackScrobbleResponse,
+            TrackSearchResponse, TrackSearchResults, TrackUpdateNowPlayingResponse,
+        },
+    },
+    utils::datetime_to_timestamp,
+};
+
+/// Identifies a track either by artist and track name or by MusicBrainz ID.
+///
+/// `(artist, track)` tuples convert into [`TrackLookup::Name`].
+#[derive(Debug, Clone)]
+pub enum TrackLookup {
+    Name { artist: String, track: String },
+    Mbid(String),
+}
+
+impl TrackLookup {
+    /// Looks the track up by artist and track name.
+    pub fn new(artist: impl Into<String>, track: impl Into<String>) -> Self {
+        Self::Name {
+            artist: artist.into(),
+            track: track.into(),
+        }
+    }
+
+    /// Looks the track up by MusicBrainz ID.
+    pub fn mbid(mbid: impl Into<String>) -> Self {
+        Self::Mbid(mbid.into())
+    }
+
+    fn append_params(&self, params: &mut BTreeMap<String, String>) {
+        match self {
+            Self::Name { artist, track } => {
+                params.insert("artist".into(), artist.clone());
+                params.insert("track".into(), track.clone());
+            }
+            Self::Mbid(mbid) => {
+                params.insert("mbid".into(), mbid.clone());
+            }
+        }
+    }
+}
+
+impl<A: Into<String>, T: Into<String>> From<(A, T)> for TrackLookup {
+    fn from((artist, track): (A, T)) -> Self {
+        Self::new(artist, track)
+    }
+}
+
+/// Maximum number of scrobbles accepted by a single `track.scrobble` call.
+pub const MAX_SCROBBLE_BATCH: usize = 50;
+
+/// A single play to submit via `track.scrobble`.
+///
+/// Only `artist`, `track` and `timestamp` are required by Last.fm.
+#[derive(Debug, Clone, Default)]
+pub struct Scrobble {
+    pub artist: String,
+    pub track: String,
+    /// The time the track started playing.
+    pub timestamp: DateTime<Utc>,
+    pub album: Option<String>,
+    pub album_artist: Option<String>,
+    pub track_number: Option<u32>,
+    /// Length of the track in seconds.
+    pub duration: Option<u32>,
+    pub mbid: Option<String>,
+    /// Whether the user picked the track themselves (as opposed to e.g. a radio stream).
+    pub chosen_by_user: Option<bool>,
+}
+
+impl Scrobble {
+    /// Creates a scrobble with the required fields set.
+    pub fn new(
+        artist: impl Into<String>,
+        track: impl Into<String>,
+        timestamp: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            artist: artist.into(),
+            track: track.into(),
+            timestamp,
+            ..Default::default()
+        }
+    }
+
+    /// Inserts this scrobble's fields using the indexed `name[i]` form.
+    fn append_params(&self, index: usize, params: &mut BTreeMap<String, String>) {
+        let mut insert = |name: &str, value: String| {
+            params.insert(format!("{name}[{index}]"), value);
+        };
+
+        insert("artist", self.artist.clone());
+        insert("track", self.track.clone());
+        insert(
+            "timestamp",
+            datetime_to_timestamp(self.timestamp).to_string(),
+        );
+
+        if let Some(album) = &self.album {
+            insert("album", album.clone());
+        }
+        if let Some(album_artist) = &self.album_artist {
+            insert("albumArtist", album_artist.clone());
+        }
+        if let Some(track_number) = self.track_number {
+            insert("trackNumber", track_number.to_string());
+        }
+        if let Some(duration) = self.duration {
+            insert("duration", duration.to_string());
+        }
+        if let Some(mbid) = &self.mbid {
+            insert("mbid", mbid.clone());
+        }
+        if let Some(chosen_by_user) = self.chosen_by_user {
+            insert("chosenByUser", u8::from(chosen_by_user).to_string());
+        }
+    }
+}
+
+/// The track to announce via `track.updateNowPlaying`.
+///
+/// Only `artist` and `track` are required by Last.fm.
+#[derive(Debug, Clone, Default)]
+pub struct NowPlaying {
+    pub artist: String,
+    pub track: String,
+    pub album: Option<String>,
+    pub album_artist: Option<String>,
+    pub track_number: Option<u32>,
+    /// Length of the track in seconds.
+    pub duration: Option<u32>,
+    pub mbid: Option<String>,
+}
+
+impl NowPlaying {
+    /// Creates a now-playing update with the required fields set.
+    pub fn new(artist: impl Into<String>, track: impl Into<String>) -> Self {
+        Self {
+            artist: artist.into(),
+            track: track.into(),
+            ..Default::default()
+        }
+    }
+
+    fn to_params(&self) -> BTreeMap<String, String> {
+        let mut params = BTreeMap::new();
+        params.insert("artist".into(), self.artist.clone());
+        params.insert("track".into(), self.track.clone());
+
+        if let Some(album) = &self.album {
+            params.insert("album".into(), album.clone());
+        }
+        if let Some(album_artist) = &self.album_artist {
+            params.insert("albumArtist".into(), album_artist.clone());
+        }
+        if let Some(track_number) = self.track_number {
+            params.insert("trackNumber".into(), track_number.to_string());
+        }
+        if let Some(duration) = self.duration {
+            params.insert("duration".into(), duration.to_string());
+        }
+        if let Some(mbid) = &self.mbid {
+            params.insert("mbid".into(), mbid.clone());
+        }
+
+        params
+    }
+}
+
+/// Page size used when reading loved tracks for [`AuthTrackHandler::sync_loved_tracks`].
+const LOVED_TRACKS_PAGE_SIZE: u32 = 1000;
+
+/// Changes made by [`AuthTrackHandler::sync_loved_tracks`], as `(artist, track)` pairs.
+#[derive(Debug, Default)]
+pub struct LovedTracksSync {
+    /// Tracks that were loved because they were desired but not yet loved.
+    pub loved: Vec<(String, String)>,
+    /// Tracks that were unloved because they were loved but not desired.
+    pub unloved: Vec<(String, String)>,
+    /// Number of desired tracks that were already loved.
+    pub unchanged: usize,
+}
+
+/// Last.fm compares artist and track names case-insensitively.
+fn loved_key(artist: &str, track: &str) -> (String, String) {
+    (artist.to_lowercase(), track.to_lowercase())
+}
+
+/// Extension trait that provides track-related API methods.
+pub trait TrackEndpointExt {
+    fn track(&self) -> TrackHandler<'_>;
+}
+
+/// Implements `track()` on the client.
+impl TrackEndpointExt for Client {
+    fn track(&self) -> TrackHandler<'_> {
+        TrackHandler { client: self }
+    }
+}
+
+/// Handles `track.*` Last.fm API methods.
+#[derive(Debug)]
+pub struct TrackHandler<'a> {
+    pub(crate) client: &'a Client,
+}
+
+impl<'a> TrackHandler<'a> {
+    /// Get the metadata for a track.
+    ///
+    /// With a `username`, the response includes that user's playcount for the track
+    /// and whether they loved it.
+    ///
+    /// [API Reference](https://www.last.fm/api/show/track.getInfo)
+    pub async fn get_info(
+        &self,
+        track: impl Into<TrackLookup>,
+        autocorrect: bool,
+        username: Option<&str>,
+    ) -> Result<TrackInfo, Error> {
+        let mut params = BTreeMap::new();
+        track.into().append_params(&mut params);
+        insert_autocorrect(&mut params, autocorrect);
+        insert_opt(&mut params, "username", username);
+
+        let response: TrackGetInfoResponse =
+            self.client.unsigned_get("track.getInfo", params).await?;
+
+        Ok(response.track)
+    }
+
+    /// Check whether a track has a correction to a canonical track.
+    ///
+    /// Returns `None` if Last.fm has no correction for the track.
+    ///
+    /// [API Reference](https://www.last.fm/api/show/track.getCorrection)
+    pub async fn get_correction(
+        &self,
+        artist: &str,
+        track: &str,
+    ) -> Result<Option<TrackCorrection>, Error> {
+        let mut params = BTreeMap::new();
+        params.insert("artist".into(), artist.to_string());
+        params.insert("track".into(), track.to_string());
+
+        let response: TrackGetCorrectionResponse = self
+            .client
+            .unsigned_get("track.getCorrection", params)
+            .await?;
+
+        Ok(response.corrections.map(|c| c.correction))
+    }
+
+    /// Get tracks similar to this one, based on listening data.
+    ///
+    /// [API Reference](https://www.last.fm/api/show/track.getSimilar)
+    pub async fn get_similar(
+        &self,
+        track: impl Into<TrackLookup>,
+        autocorrect: bool,
+        limit: Option<u32>,
+    ) -> Result<SimilarTracks, Error> {
+        let mut params = BTreeMap::new();
+        track.into().append_params(&mut params);
+        insert_autocorrect(&mut params, autocorrect);
+        insert_opt(&mut params, "limit", limit);
+
+        let response: TrackGetSimilarResponse =
+            self.client.unsigned_get("track.getSimilar", params).await?;
+
+        Ok(response.similartracks)
+    }
+
+    /// Get the tags `username` applied to a track.
+    ///
+    /// [API Reference](https://www.last.fm/api/show/track.getTags)
+    pub async fn get_tags(
+        &self,
+        track: impl Into<TrackLookup>,
+        autocorrect: bool,
+        username: &str,
+    ) -> Result<Vec<Tag>, Error> {
+        let mut params = BTreeMap::new();
+        track.into().append_params(&mut params);
+        insert_autocorrect(&mut params, autocorrect);
+        params.insert("user".into(), username.to_string());
+
+        let response: TrackGetTagsResponse =
+            self.client.unsigned_get("track.getTags", params).await?;
+
+        Ok(response.tags.tag)
+    }
+
+    /// Get the top tags for a track, ordered by popularity.
+    ///
+    /// [API Reference](https://www.last.fm/api/show/track.getTopTags)
+    pub async fn get_top_tags(
+        &self,
+        track: impl Into<TrackLookup>,
+        autocorrect: bool,
+    ) -> Result<TopTags, Error> {
+        let mut params = BTreeMap::new();
+        track.into().append_params(&mut params);
+        insert_autocorrect(&mut params, autocorrect);
+
+        let response: TrackGetTopTagsResponse =
+            self.client.unsigned_get("track.getTopTags", params).await?;
+
+        Ok(response.toptags)
+    }
+
+    /// Search for a track by name, optionally narrowed to an artist, ordered by relevance.
+    ///
+    /// [API Reference](https://www.last.fm/api/show/track.search)
+    pub async fn search(
+        &self,
+        track: &str,
+        artist: Option<&str>,
+        limit: Option<u32>,
+        page: Option<u32>,
+    ) -> Result<TrackSearchResults, Error> {
+        let mut params = BTreeMap::new();
+        params.insert("track".into(), track.to_string());
+        insert_opt(&mut params, "artist", artist);
+        insert_opt(&mut params, "limit", limit);
+        insert_opt(&mut params, "page", page);
+
+        let response: TrackSearchResponse =
+            self.client.unsigned_get("track.search", params).await?;
+
+        Ok(response.results)
+    }
+}
+
+/// Extension trait that provides track-related write methods.
+pub trait AuthTrackEndpointExt {
+    fn track(&self) -> AuthTrackHandler<'_>;
+}
+
+/// Implements `track()` on the authenticated client.
+impl AuthTrackEndpointExt for AuthenticatedClient {
+    fn track(&self) -> AuthTrackHandler<'_> {
+        AuthTrackHandler { client: self }
+    }
+}
+
+/// Handles `track.*` Last.fm API methods that act on the session user's account.
+#[derive(Debug)]
+pub struct AuthTrackHandler<'a> {
+    pub(crate) client: &'a AuthenticatedClient,
+}
+
+impl<'a> AuthTrackHandler<'a> {
+    /// Scrobble a single track on behalf of the session user.
+    ///
+    /// [API Reference](https://www.last.fm/api/show/track.scrobble)
+    pub async fn scrobble(&self, scrobble: &Scrobble) -> Result<Scrobbles, Error> {
+        self.scrobble_batch(std::slice::from_ref(scrobble)).await
+    }
+
+    /// Scrobble up to [`MAX_SCROBBLE_BATCH`] tracks in a single request.
+    ///
+    /// The returned [`Scrobbles`] lists one result per submitted scrobble, in order.
+    ///
+    /// [API Reference](https://www.last.fm/api/show/track.scrobble)
+    pub async fn scrobble_batch(&self, scrobbles: &[Scrobble]) -> Result<Scrobbles, Error> {
+        if scrobbles.is_empty() {
+            return Err(Error::InvalidArgument(
+                "at least one scrobble is required".into(),
+            ));
+        }
+        if scrobbles.len() > MAX_SCROBBLE_BATCH {
+            return Err(Error::InvalidArgument(format!(
+                "at most {MAX_SCROBBLE_BATCH} scrobbles can be sent per request, got {}",
+                scrobbles.len()
+            )));
+        }
+
+        let mut params = BTreeMap::new();
+        for (index, scrobble) in scrobbles.iter().enumerate() {
+            scrobble.append_params(index, &mut params);
+        }
+
+        let response: TrackScrobbleResponse =
+            self.client.signed_post("track.scrobble", params).await?;
+
+        Ok(response.scrobbles)
+    }
+
+    /// Notify Last.fm that the session user started listening to a track.
+    ///
+    /// [API Reference](https://www.last.fm/api/show/track.updateNowPlaying)
+    pub async fn update_now_playing(
+        &self,
+        now_playing: &NowPlaying,
+    ) -> Result<NowPlayingResult, Error> {
+        let response: TrackUpdateNowPlayingResponse = self
+            .client
+            .signed_post("track.updateNowPlaying", now_playing.to_params())
+            .await?;
+
+        Ok(response.nowplaying)
+    }
+
+    /// Love a track for the session user.
+    ///
+    /// [API Reference](https://www.last.fm/api/show/track.love)
+    pub async fn love(&self, artist: &str, track: &str) -> Result<(), Error> {
+        self.set_loved("track.love", artist, track).await
+    }
+
+    /// Unlove a track for the session user.
+    ///
+    /// [API Reference](https://www.last.fm/api/show/track.unlove)
+    pub async fn unlove(&self, artist: &str, track: &str) -> Result<(), Error> {
+        self.set_loved("track.unlove", artist, track).await
+    }
+
+    async fn set_loved(&self, method: &str, artist: &str, track: &str) -> Result<(), Error> {
+        let mut params = BTreeMap::new();
+        params.insert("artist".into(), artist.to_string());
+        params.insert("track".into(), track.to_string());
+
+        let _: IgnoredAny = self.client.signed_post(method, params).await?;
+
+        Ok(())
+    }
+
+    /// Make the session user's loved tracks match `desired`.
+    ///
+    /// Reads every page of `user.getLovedTracks`, then loves the desired tracks that are
+    /// missing and unloves the loved tracks that aren't desired. Names are compared
+    /// case-insensitively.
+    ///
+    /// Stops at the first failing call; changes made before it are not rolled back.
+    pub async fn sync_loved_tracks<I, A, T>(&self, desired: I) -> Result<LovedTracksSync, Error>
+    where
+        I: IntoIterator<Item = (A, T)>,
+        A: Into<String>,
+        T: Into<String>,
+    {
+        let mut current = Vec::new();
+        let mut page = 1;
+        loop {
+            let loved = self
+                .client
+                .client()
+                .user()
+                .get_loved_tracks_page(
+                    self.client.username(),
+                    Some(LOVED_TRACKS_PAGE_SIZE),
+                    Some(page),
+                )
+                .await?;
+
+            if loved.track.is_empty() {
+                break;
+            }
+            current.extend(
+                loved
+                    .track
+                    .into_iter()
+                    .map(|track| (track.artist.name, track.name)),
+            );
+
+            if page >= loved.attr.total_pages {
+                break;
+            }
+            page += 1;
+        }
+
+        let current_keys: HashSet<_> = current
+            .iter()
+            .map(|(artist, track)| loved_key(artist, track))
+            .collect();
+
+        let mut report = LovedTracksSync::default();
+        let mut desired_keys = HashSet::new();
+        for (artist, track) in desired {
+            let (artist, track) = (artist.into(), track.into());
+            let key = loved_key(&artist, &track);
+
+            if !desired_keys.insert(key.clone()) {
+                continue;
+            }
+            if current_keys.contains(&key) {
+                report.unchanged += 1;
+            } else {
+                self.love(&artist, &track).await?;
+                report.loved.push((artist, track));
+            }
+        }
+
+        for (artist, track) in current {
+            if !desired_keys.contains(&loved_key(&artist, &track)) {
+                self.unlove(&artist, &track).await?;
+                report.unloved.push((artist, track));
+            }
+        }
+
+        Ok(report)
+    }
+}
@@ -1,16 +1,127 @@
 //! User API methods for Last.fm.
 
 use std::collections::BTreeMap;
+use std::fmt;
+
+use chrono::{DateTime, Utc};
+use serde::de::DeserializeOwned;
+use serde::{Serialize, Serializer};
 
 use crate::{
     client::Client,
+    endpoints::common::insert_opt,
     error::Error,
+    models::common::ChartRange,
     models::user::{
-        LovedTracks, UserFriends, UserGetFriendsResponse, UserGetInfoResponse,
-        UserGetLovedTracksResponse, UserInfo,
+        LovedTracks, PersonalTags, RecentTracks, UserFriends, UserGetFriendsResponse,
+        UserGetInfoResponse, UserGetLovedTracksResponse, UserGetPersonalTagsResponse,
+        UserGetRecentTracksResponse, UserGetTopAlbumsResponse, UserGetTopArtistsResponse,
+        UserGetTopTagsResponse, UserGetTopTracksResponse, UserGetWeeklyAlbumChartResponse,
+        UserGetWeeklyArtistChartResponse, UserGetWeeklyChartListResponse,
+        UserGetWeeklyTrackChartResponse, UserInfo, UserTopAlbums, UserTopArtists, UserTopTags,
+        UserTopTracks, WeeklyAlbumChart, WeeklyArtistChart, WeeklyTrackChart,
     },
+    utils::datetime_to_timestamp,
 };
 
+/// The time range covered by `user.getTop*` methods.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub enum Period {
+    #[default]
+    Overall,
+    SevenDay,
+    OneMonth,
+    ThreeMonth,
+    SixMonth,
+    TwelveMonth,
+}
+
+impl Period {
+    /// The value Last.fm expects for the `period` parameter.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Overall => "overall",
+            Self::SevenDay => "7day",
+            Self::OneMonth => "1month",
+            Self::ThreeMonth => "3month",
+            Self::SixMonth => "6month",
+            Self::TwelveMonth => "12month",
+        }
+    }
+}
+
+impl fmt::Display for Period {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Serialize for Period {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+/// The kind of items to list in `user.getPersonalTags`.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum TaggingType {
+    Artist,
+    Album,
+    Track,
+}
+
+impl TaggingType {
+    /// The value Last.fm expects for the `taggingtype` parameter.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Artist => "artist",
+            Self::Album => "album",
+            Self::Track => "track",
+        }
+    }
+}
+
+/// A weekly chart that can be fetched with [`UserHandler::get_weekly_charts_between`].
+///
+/// Implemented for [`WeeklyArtistChart`], [`WeeklyAlbumChart`] and [`WeeklyTrackChart`].
+pub trait WeeklyChart: Sized {
+    /// The Last.fm method that returns this chart.
+    const METHOD: &'static str;
+
+    #[doc(hidden)]
+    type Response: DeserializeOwned;
+
+    #[doc(hidden)]
+    fn from_response(response: Self::Response) -> Self;
+}
+
+impl WeeklyChart for WeeklyArtistChart {
+    const METHOD: &'static str = "user.getWeeklyArtistChart";
+    type Response = UserGetWeeklyArtistChartResponse;
+
+    fn from_response(response: Self::Response) -> Self {
+        response.weeklyartistchart
+    }
+}
+
+impl WeeklyChart for WeeklyAlbumChart {
+    const METHOD: &'static str = "user.getWeeklyAlbumChart";
+    type Response = UserGetWeeklyAlbumChartResponse;
+
+    fn from_response(response: Self::Response) -> Self {
+        response.weeklyalbumchart
+    }
+}
+
+impl WeeklyChart for WeeklyTrackChart {
+    const METHOD: &'static str = "user.getWeeklyTrackChart";
+    type Response = UserGetWeeklyTrackChartResponse;
+
+    fn from_response(response: Self::Response) -> Self {
+        response.weeklytrackchart
+    }
+}
+
 /// Extension trait that provides user-related API methods.
 pub trait UserEndpointExt {
     fn user(&self) -> UserHandler<'_>;
@@ -47,8 +158,24 @@ impl<'a> UserHandler<'a> {
     ///
     /// [API Reference](https://www.last.fm/api/show/user.getFriends)
     pub async fn get_friends(&self, username: &str) -> Result<UserFriends, Error> {
+        self.get_friends_page(username, None, None).await
+    }
+
+    /// Get a single page of friends for a Last.fm user.
+    ///
+    /// `limit` defaults to 50; `page` starts at 1.
+    ///
+    /// [API Reference](https://www.last.fm/api/show/user.getFriends)
+    pub async fn get_friends_page(
+        &self,
+        username: &str,
+        limit: Option<u32>,
+        page: Option<u32>,
+    ) -> Result<UserFriends, Error> {
         let mut params = BTreeMap::new();
         params.insert("user".into(), username.to_string());
+        insert_opt(&mut params, "limit", limit);
+        insert_opt(&mut params, "page", page);
 
         let response: UserGetFriendsResponse =
             self.client.unsigned_get("user.getFriends", params).await?;
@@ -60,8 +187,24 @@ impl<'a> UserHandler<'a> {
     ///
     /// [API Reference](https://www.last.fm/api/show/user.getLovedTracks)
     pub async fn get_loved_tracks(&self, username: &str) -> Result<LovedTracks, Error> {
+        self.get_loved_tracks_page(username, None, None).await
+    }
+
+    /// Get a single page of loved tracks for a Last.fm user.
+    ///
+    /// `limit` defaults to 50 and may be at most 1000; `page` starts at 1.
+    ///
+    /// [API Reference](https://www.last.fm/api/show/user.getLovedTracks)
+    pub async fn get_loved_tracks_page(
+        &self,
+        username: &str,
+        limit: Option<u32>,
+        page: Option<u32>,
+    ) -> Result<LovedTracks, Error> {
         let mut params = BTreeMap::new();
         params.insert("user".into(), username.to_string());
+        insert_opt(&mut params, "limit", limit);
+        insert_opt(&mut params, "page", page);
 
         let response: UserGetLovedTracksResponse = self
             .client
@@ -70,4 +213,277 @@ impl<'a> UserHandler<'a> {
 
         Ok(response.lovedtracks)
     }
+
+    /// Get the tracks a user recently scrobbled, newest first.
+    ///
+    /// `from` and `to` limit the results to scrobbles in that window. In `extended` mode
+    /// each track carries the full artist object and whether the user loved it.
+    ///
+    /// [API Reference](https://www.last.fm/api/show/user.getRecentTracks)
+    pub async fn get_recent_tracks(
+        &self,
+        username: &str,
+        from: Option<DateTime<Utc>>,
+        to: Option<DateTime<Utc>>,
+        limit: Option<u32>,
+        page: Option<u32>,
+        extended: bool,
+    ) -> Result<RecentTracks, Error> {
+        let mut params = BTreeMap::new();
+        params.insert("user".into(), username.to_string());
+        insert_opt(&mut params, "from", from.map(datetime_to_timestamp));
+        insert_opt(&mut params, "to", to.map(datetime_to_timestamp));
+        insert_opt(&mut params, "limit", limit);
+        insert_opt(&mut params, "page", page);
+
+        if extended {
+            params.insert("extended".into(), "1".into());
+        }
+
+        let response: UserGetRecentTracksResponse = self
+            .client
+            .unsigned_get("user.getRecentTracks", params)
+            .await?;
+
+        Ok(response.recenttracks)
+    }
+
+    /// Get a user's most played artists over `period`.
+    ///
+    /// [API Reference](https://www.last.fm/api/show/user.getTopArtists)
+    pub async fn get_top_artists(
+        &self,
+        username: &str,
+        period: Period,
+        limit: Option<u32>,
+        page: Option<u32>,
+    ) -> Result<UserTopArtists, Error> {
+        let params = top_params(username, period, limit, page);
+
+        let response: UserGetTopArtistsResponse = self
+            .client
+            .unsigned_get("user.getTopArtists", params)
+            .await?;
+
+        Ok(response.topartists)
+    }
+
+    /// Get a user's most played albums over `period`.
+    ///
+    /// [API Reference](https://www.last.fm/api/show/user.getTopAlbums)
+    pub async fn get_top_albums(
+        &self,
+        username: &str,
+        period: Period,
+        limit: Option<u32>,
+        page: Option<u32>,
+    ) -> Result<UserTopAlbums, Error> {
+        let params = top_params(username, period, limit, page);
+
+        let response: UserGetTopAlbumsResponse = self
+            .client
+            .unsigned_get("user.getTopAlbums", params)
+            .await?;
+
+        Ok(response.topalbums)
+    }
+
+    /// Get a user's most played tracks over `period`.
+    ///
+    /// [API Reference](https://www.last.fm/api/show/user.getTopTracks)
+    pub async fn get_top_tracks(
+        &self,
+        username: &str,
+        period: Period,
+        limit: Option<u32>,
+        page: Option<u32>,
+    ) -> Result<UserTopTracks, Error> {
+        let params = top_params(username, period, limit, page);
+
+        let response: UserGetTopTracksResponse = self
+            .client
+            .unsigned_get("user.getTopTracks", params)
+            .await?;
+
+        Ok(response.toptracks)
+    }
+
+    /// Get the tags a user applied most often.
+    ///
+    /// Last.fm neither filters this list by period nor paginates it.
+    ///
+    /// [API Reference](https://www.last.fm/api/show/user.getTopTags)
+    pub async fn get_top_tags(
+        &self,
+        username: &str,
+        limit: Option<u32>,
+    ) -> Result<UserTopTags, Error> {
+        let mut params = BTreeMap::new();
+        params.insert("user".into(), username.to_string());
+        insert_opt(&mut params, "limit", limit);
+
+        let response: UserGetTopTagsResponse =
+            self.client.unsigned_get("user.getTopTags", params).await?;
+
+        Ok(response.toptags)
+    }
+
+    /// Get the artists, albums or tracks a user tagged with `tag`.
+    ///
+    /// The variant of [`PersonalTags::items`] matches `tagging_type`.
+    ///
+    /// [API Reference](https://www.last.fm/api/show/user.getPersonalTags)
+    pub async fn get_personal_tags(
+        &self,
+        username: &str,
+        tag: &str,
+        tagging_type: TaggingType,
+        limit: Option<u32>,
+        page: Option<u32>,
+    ) -> Result<PersonalTags, Error> {
+        let mut params = BTreeMap::new();
+        params.insert("user".into(), username.to_string());
+        params.insert("tag".into(), tag.to_string());
+        params.insert("taggingtype".into(), tagging_type.as_str().into());
+        insert_opt(&mut params, "limit", limit);
+        insert_opt(&mut params, "page", page);
+
+        let response: UserGetPersonalTagsResponse = self
+            .client
+            .unsigned_get("user.getPersonalTags", params)
+            .await?;
+
+        Ok(response.taggings)
+    }
+
+    /// Get the date ranges of the weekly charts available for a user.
+    ///
+    /// [API Reference](https://www.last.fm/api/show/user.getWeeklyChartList)
+    pub async fn get_weekly_chart_list(&self, username: &str) -> Result<Vec<ChartRange>, Error> {
+        let mut params = BTreeMap::new();
+        params.insert("user".into(), username.to_string());
+
+        let response: UserGetWeeklyChartListResponse = self
+            .client
+            .unsigned_get("user.getWeeklyChartList", params)
+            .await?;
+
+        Ok(response.weeklychartlist.chart)
+    }
+
+    /// Get a user's weekly artist chart.
+    ///
+    /// `range` should come from [`get_weekly_chart_list`](Self::get_weekly_chart_list);
+    /// without it Last.fm returns the most recent week.
+    ///
+    /// [API Reference](https://www.last.fm/api/show/user.getWeeklyArtistChart)
+    pub async fn get_weekly_artist_chart(
+        &self,
+        username: &str,
+        range: Option<ChartRange>,
+    ) -> Result<WeeklyArtistChart, Error> {
+        self.get_weekly_chart(username, range).await
+    }
+
+    /// Get a user's weekly album chart.
+    ///
+    /// `range` should come from [`get_weekly_chart_list`](Self::get_weekly_chart_list);
+    /// without it Last.fm returns the most recent week.
+    ///
+    /// [API Reference](https://www.last.fm/api/show/user.getWeeklyAlbumChart)
+    pub async fn get_weekly_album_chart(
+        &self,
+        username: &str,
+        range: Option<ChartRange>,
+    ) -> Result<WeeklyAlbumChart, Error> {
+        self.get_weekly_chart(username, range).await
+    }
+
+    /// Get a user's weekly track chart.
+    ///
+    /// `range` should come from [`get_weekly_chart_list`](Self::get_weekly_chart_list);
+    /// without it Last.fm returns the most recent week.
+    ///
+    /// [API Reference](https://www.last.fm/api/show/user.getWeeklyTrackChart)
+    pub async fn get_weekly_track_chart(
+        &self,
+        username: &str,
+        range: Option<ChartRange>,
+    ) -> Result<WeeklyTrackChart, Error> {
+        self.get_weekly_chart(username, range).await
+    }
+
+    /// Fetch every weekly chart of kind `C` that lies entirely between `from` and `to`.
+    ///
+    /// Weeks are read from `user.getWeeklyChartList` and fetched one after another,
+    /// oldest first.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn run(client: soniq::Client) -> Result<(), soniq::Error> {
+    /// use chrono::{Duration, Utc};
+    /// use soniq::models::user::WeeklyArtistChart;
+    ///
+    /// let to = Utc::now();
+    /// let charts: Vec<WeeklyArtistChart> = client
+    ///     .user()
+    ///     .get_weekly_charts_between("RJ", to - Duration::weeks(4), to)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_weekly_charts_between<C: WeeklyChart>(
+        &self,
+        username: &str,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> Result<Vec<C>, Error> {
+        let mut ranges: Vec<_> = self
+            .get_weekly_chart_list(username)
+            .await?
+            .into_iter()
+            .filter(|range| range.from >= from && range.to <= to)
+            .collect();
+        ranges.sort_by_key(|range| range.from);
+
+        let mut charts = Vec::with_capacity(ranges.len());
+        for range in ranges {
+            charts.push(self.get_weekly_chart(username, Some(range)).await?);
+        }
+
+        Ok(charts)
+    }
+
+    async fn get_weekly_chart<C: WeeklyChart>(
+        &self,
+        username: &str,
+        range: Option<ChartRange>,
+    ) -> Result<C, Error> {
+        let mut params = BTreeMap::new();
+        params.insert("user".into(), username.to_string());
+
+        if let Some(range) = range {
+            params.insert("from".into(), datetime_to_timestamp(range.from).to_string());
+            params.insert("to".into(), datetime_to_timestamp(range.to).to_string());
+        }
+
+        let response: C::Response = self.client.unsigned_get(C::METHOD, params).await?;
+
+        Ok(C::from_response(response))
+    }
+}
+
+fn top_params(
+    username: &str,
+    period: Period,
+    limit: Option<u32>,
+    page: Option<u32>,
+) -> BTreeMap<String, String> {
+    let mut params = BTreeMap::new();
+    params.insert("user".into(), username.to_string());
+    params.insert("period".into(), period.to_string());
+    insert_opt(&mut params, "limit", limit);
+    insert_opt(&mut params, "page", page);
+    params
 }
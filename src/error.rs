@@ -11,6 +11,14 @@ pub enum Error {
     #[error("API secret is required for signed calls")]
     MissingApiSecret,
 
+    /// An argument was rejected before the request was sent.
+    #[error("Invalid argument: {0}")]
+    InvalidArgument(String),
+
+    /// An authentication token expired before the user authorized it.
+    #[error("Authentication token expired before it was authorized")]
+    TokenExpired,
+
     /// A Last.fm-specific error response.
     /// See [`ErrorResponse`].
     #[error("Last.fm API error: {0}")]
@@ -21,19 +29,59 @@ pub enum Error {
     #[error("HTTP error {status}: {text}")]
     Http { status: StatusCode, text: String },
 
+    /// Last.fm answered with an empty body.
+    #[error("Empty response (HTTP {status})")]
+    EmptyResponse { status: StatusCode },
+
+    /// Last.fm answered with an HTML page instead of JSON, usually a maintenance
+    /// or outage page.
+    #[error("Unexpected HTML page instead of JSON (HTTP {status})")]
+    HtmlPage {
+        status: StatusCode,
+        /// The page's `<title>`, if it has one.
+        title: Option<String>,
+    },
+
     /// A network or request-related error from the underlying HTTP client.
     #[error("Request error: {0}")]
     Request(#[from] reqwest::Error),
 
+    /// A response didn't match the model expected for its method.
+    #[error("Failed to parse `{method}` response at `{path}`: {source} (near: {excerpt})")]
+    Deserialize {
+        /// The Last.fm method, e.g. `user.getInfo`.
+        method: String,
+        /// Path of the field that failed, e.g. `user.registered.unixtime`.
+        path: String,
+        #[source]
+        source: serde_json::Error,
+        /// The body around the failing position, with API and session keys masked.
+        excerpt: String,
+    },
+
     /// A JSON deserialization error.
     #[error("JSON deserialization error: {0}")]
     Json(#[from] serde_json::Error),
 
+    /// An I/O error, e.g. from the local auth callback listener.
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+
     /// An error parsing a URL.
     #[error("URL parse error: {0}")]
     UrlParse(#[from] url::ParseError),
 }
 
+impl Error {
+    /// The Last.fm error code, if this is an error reported by Last.fm.
+    pub fn code(&self) -> Option<LastFmErrorCode> {
+        match self {
+            Self::LastFm(err) => Some(err.code()),
+            _ => None,
+        }
+    }
+}
+
 /// An error response from the Last.fm API.
 ///
 /// See <https://www.last.fm/api/errorcodes> for a list of error codes.
@@ -43,6 +91,13 @@ pub struct ErrorResponse {
     pub message: String,
 }
 
+impl ErrorResponse {
+    /// The typed error code.
+    pub fn code(&self) -> LastFmErrorCode {
+        LastFmErrorCode::from(self.error)
+    }
+}
+
 impl fmt::Display for ErrorResponse {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "Error {}: {}", self.error, self.message)
@@ -50,3 +105,180 @@ impl fmt::Display for ErrorResponse {
 }
 
 impl std::error::Error for ErrorResponse {}
+
+/// Error codes documented by Last.fm.
+///
+/// See <https://www.last.fm/api/errorcodes>.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum LastFmErrorCode {
+    /// 2: This service does not exist.
+    InvalidService,
+    /// 3: No method with that name in this package.
+    InvalidMethod,
+    /// 4: You do not have permissions to access the service.
+    AuthenticationFailed,
+    /// 5: This service doesn't exist in that format.
+    InvalidFormat,
+    /// 6: Your request is missing a required parameter.
+    InvalidParameters,
+    /// 7: Invalid resource specified.
+    InvalidResource,
+    /// 8: Something else went wrong.
+    OperationFailed,
+    /// 9: Please re-authenticate.
+    InvalidSessionKey,
+    /// 10: You must be granted a valid key by Last.fm.
+    InvalidApiKey,
+    /// 11: This service is temporarily offline. Try again later.
+    ServiceOffline,
+    /// 12: This station is only available to paid Last.fm subscribers.
+    SubscribersOnly,
+    /// 13: Invalid method signature supplied.
+    InvalidMethodSignature,
+    /// 14: This token has not been authorized.
+    UnauthorizedToken,
+    /// 15: This token has expired.
+    TokenExpired,
+    /// 16: There was a temporary error processing your request. Please try again.
+    TemporarilyUnavailable,
+    /// 17: User requires to be logged in.
+    LoginRequired,
+    /// 18: This user has no free radio plays left.
+    TrialExpired,
+    /// 20: There is not enough content to play this station.
+    NotEnoughContent,
+    /// 21: This group does not have enough members for radio.
+    NotEnoughMembers,
+    /// 22: This artist does not have enough fans for radio.
+    NotEnoughFans,
+    /// 23: There are not enough neighbours for radio.
+    NotEnoughNeighbours,
+    /// 24: No peak radio for this user.
+    NoPeakRadio,
+    /// 25: Radio station not found.
+    RadioNotFound,
+    /// 26: This application's access has been suspended.
+    ApiKeySuspended,
+    /// 27: This type of request is no longer supported.
+    Deprecated,
+    /// 29: Your IP has made too many requests in a short period.
+    RateLimitExceeded,
+    /// A code not documented at the time of writing.
+    Unknown(u32),
+}
+
+impl LastFmErrorCode {
+    /// The numeric code.
+    pub fn as_u32(self) -> u32 {
+        match self {
+            Self::InvalidService => 2,
+            Self::InvalidMethod => 3,
+            Self::AuthenticationFailed => 4,
+            Self::InvalidFormat => 5,
+            Self::InvalidParameters => 6,
+            Self::InvalidResource => 7,
+            Self::OperationFailed => 8,
+            Self::InvalidSessionKey => 9,
+            Self::InvalidApiKey => 10,
+            Self::ServiceOffline => 11,
+            Self::SubscribersOnly => 12,
+            Self::InvalidMethodSignature => 13,
+            Self::UnauthorizedToken => 14,
+            Self::TokenExpired => 15,
+            Self::TemporarilyUnavailable => 16,
+            Self::LoginRequired => 17,
+            Self::TrialExpired => 18,
+            Self::NotEnoughContent => 20,
+            Self::NotEnoughMembers => 21,
+            Self::NotEnoughFans => 22,
+            Self::NotEnoughNeighbours => 23,
+            Self::NoPeakRadio => 24,
+            Self::RadioNotFound => 25,
+            Self::ApiKeySuspended => 26,
+            Self::Deprecated => 27,
+            Self::RateLimitExceeded => 29,
+            Self::Unknown(code) => code,
+        }
+    }
+
+    /// Whether the error is temporary, so the same request may succeed later.
+    pub fn is_retryable(self) -> bool {
+        matches!(
+            self,
+            Self::OperationFailed
+                | Self::ServiceOffline
+                | Self::TemporarilyUnavailable
+                | Self::RateLimitExceeded
+        )
+    }
+
+    /// Whether the API key, signature, token or session was rejected.
+    pub fn is_auth_error(self) -> bool {
+        matches!(
+            self,
+            Self::AuthenticationFailed
+                | Self::InvalidSessionKey
+                | Self::InvalidApiKey
+                | Self::InvalidMethodSignature
+                | Self::UnauthorizedToken
+                | Self::TokenExpired
+                | Self::LoginRequired
+                | Self::ApiKeySuspended
+        )
+    }
+
+    /// Whether the request itself was at fault and must be changed before it can
+    /// succeed. This includes all auth errors.
+    pub fn is_client_error(self) -> bool {
+        self.is_auth_error()
+            || matches!(
+                self,
+                Self::InvalidService
+                    | Self::InvalidMethod
+                    | Self::InvalidFormat
+                    | Self::InvalidParameters
+                    | Self::InvalidResource
+                    | Self::Deprecated
+            )
+    }
+}
+
+impl From<u32> for LastFmErrorCode {
+    fn from(code: u32) -> Self {
+        match code {
+            2 => Self::InvalidService,
+            3 => Self::InvalidMethod,
+            4 => Self::AuthenticationFailed,
+            5 => Self::InvalidFormat,
+            6 => Self::InvalidParameters,
+            7 => Self::InvalidResource,
+            8 => Self::OperationFailed,
+            9 => Self::InvalidSessionKey,
+            10 => Self::InvalidApiKey,
+            11 => Self::ServiceOffline,
+            12 => Self::SubscribersOnly,
+            13 => Self::InvalidMethodSignature,
+            14 => Self::UnauthorizedToken,
+            15 => Self::TokenExpired,
+            16 => Self::TemporarilyUnavailable,
+            17 => Self::LoginRequired,
+            18 => Self::TrialExpired,
+            20 => Self::NotEnoughContent,
+            21 => Self::NotEnoughMembers,
+            22 => Self::NotEnoughFans,
+            23 => Self::NotEnoughNeighbours,
+            24 => Self::NoPeakRadio,
+            25 => Self::RadioNotFound,
+            26 => Self::ApiKeySuspended,
+            27 => Self::Deprecated,
+            29 => Self::RateLimitExceeded,
+            code => Self::Unknown(code),
+        }
+    }
+}
+
+impl From<LastFmErrorCode> for u32 {
+    fn from(code: LastFmErrorCode) -> Self {
+        code.as_u32()
+    }
+}
@@ -7,6 +7,10 @@ pub mod client;
 pub mod endpoints;
 pub mod error;
 pub mod models;
+pub mod paginate;
+pub mod rate_limit;
+pub mod retry;
+pub mod session;
 pub mod sig;
 pub mod utils;
 
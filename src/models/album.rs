@@ -0,0 +1,116 @@
+//! Models for album-related Last.fm API responses.
+
+use serde::Deserialize;
+
+use crate::models::common::{ArtistRef, Image, RankAttr, SearchMeta, Tags, TopTags, Wiki};
+use crate::models::user::Streamable;
+use crate::utils::{blank_as_default, from_str, from_str_opt, one_or_many};
+
+/// Response wrapper from the API: `{ "album": { ... } }`
+#[derive(Debug, Deserialize)]
+pub struct AlbumGetInfoResponse {
+    pub album: AlbumInfo,
+}
+
+/// Main album info object returned by `album.getInfo`
+#[derive(Debug, Deserialize)]
+pub struct AlbumInfo {
+    pub name: String,
+    pub artist: String,
+    pub mbid: Option<String>,
+    pub url: String,
+
+    #[serde(default)]
+    pub image: Vec<Image>,
+
+    #[serde(deserialize_with = "from_str")]
+    pub listeners: u64,
+
+    #[serde(deserialize_with = "from_str")]
+    pub playcount: u64,
+
+    /// Only present when a `username` was given.
+    #[serde(default, deserialize_with = "from_str_opt")]
+    pub userplaycount: Option<u64>,
+
+    #[serde(default, deserialize_with = "blank_as_default")]
+    pub tags: Tags,
+
+    #[serde(default, deserialize_with = "blank_as_default")]
+    pub tracks: AlbumTracks,
+
+    pub wiki: Option<Wiki>,
+}
+
+/// The tracklist of an album.
+#[derive(Debug, Default, Deserialize)]
+pub struct AlbumTracks {
+    #[serde(default, deserialize_with = "one_or_many")]
+    pub track: Vec<AlbumTrack>,
+}
+
+/// A single track on an album.
+#[derive(Debug, Deserialize)]
+pub struct AlbumTrack {
+    pub name: String,
+    pub url: String,
+
+    /// Length of the track in seconds, if known.
+    #[serde(default, deserialize_with = "from_str_opt")]
+    pub duration: Option<u32>,
+
+    pub artist: ArtistRef,
+    pub streamable: Option<Streamable>,
+
+    /// Position on the album, starting at 1.
+    #[serde(rename = "@attr")]
+    pub attr: RankAttr,
+}
+
+/// Response wrapper from the API: `{ "tags": { ... } }`
+#[derive(Debug, Deserialize)]
+pub struct AlbumGetTagsResponse {
+    #[serde(deserialize_with = "blank_as_default")]
+    pub tags: Tags,
+}
+
+/// Response wrapper from the API: `{ "toptags": { ... } }`
+#[derive(Debug, Deserialize)]
+pub struct AlbumGetTopTagsResponse {
+    #[serde(deserialize_with = "blank_as_default")]
+    pub toptags: TopTags,
+}
+
+/// Response wrapper from the API: `{ "results": { ... } }`
+#[derive(Debug, Deserialize)]
+pub struct AlbumSearchResponse {
+    pub results: AlbumSearchResults,
+}
+
+/// Results of an `album.search` call.
+#[derive(Debug, Deserialize)]
+pub struct AlbumSearchResults {
+    #[serde(flatten)]
+    pub meta: SearchMeta,
+
+    pub albummatches: AlbumMatches,
+}
+
+/// The albums matching a search.
+#[derive(Debug, Deserialize)]
+pub struct AlbumMatches {
+    #[serde(default, deserialize_with = "one_or_many")]
+    pub album: Vec<AlbumMatch>,
+}
+
+/// A single album search result.
+#[derive(Debug, Deserialize)]
+pub struct AlbumMatch {
+    pub name: String,
+    pub artist: String,
+    pub mbid: Option<String>,
+    pub url: String,
+
+    #[serde(default)]
+    pub image: Vec<Image>,
+}
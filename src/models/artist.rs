@@ -0,0 +1,225 @@
+//! Models for artist-related Last.fm API responses.
+
+use serde::Deserialize;
+
+use crate::models::common::{
+    ArtistRef, Image, PaginationMeta, RankAttr, SearchMeta, Stats, Tags, TopTags, Wiki,
+};
+use crate::utils::{blank_as_default, from_str, from_str_bool, one_or_many};
+
+/// Response wrapper from the API: `{ "artist": { ... } }`
+#[derive(Debug, Deserialize)]
+pub struct ArtistGetInfoResponse {
+    pub artist: ArtistInfo,
+}
+
+/// Main artist info object returned by `artist.getInfo`
+#[derive(Debug, Deserialize)]
+pub struct ArtistInfo {
+    pub name: String,
+    pub mbid: Option<String>,
+    pub url: String,
+
+    #[serde(default)]
+    pub image: Vec<Image>,
+
+    #[serde(deserialize_with = "from_str_bool")]
+    pub streamable: bool,
+
+    #[serde(deserialize_with = "from_str_bool")]
+    pub ontour: bool,
+
+    pub stats: Stats,
+    pub similar: SimilarArtistRefs,
+
+    #[serde(default, deserialize_with = "blank_as_default")]
+    pub tags: Tags,
+    pub bio: Option<Wiki>,
+}
+
+/// The short list of similar artists embedded in [`ArtistInfo`].
+#[derive(Debug, Deserialize)]
+pub struct SimilarArtistRefs {
+    #[serde(default, deserialize_with = "one_or_many")]
+    pub artist: Vec<SimilarArtistRef>,
+}
+
+/// A similar artist embedded in [`ArtistInfo`].
+#[derive(Debug, Deserialize)]
+pub struct SimilarArtistRef {
+    pub name: String,
+    pub url: String,
+
+    #[serde(default)]
+    pub image: Vec<Image>,
+}
+
+/// Response wrapper from the API: `{ "corrections": { ... } }`
+#[derive(Debug, Deserialize)]
+pub struct ArtistGetCorrectionResponse {
+    /// `None` if Last.fm has no correction for the name.
+    #[serde(deserialize_with = "blank_as_default")]
+    pub corrections: Option<ArtistCorrections>,
+}
+
+/// Wrapper around the single correction returned by `artist.getCorrection`.
+#[derive(Debug, Deserialize)]
+pub struct ArtistCorrections {
+    pub correction: ArtistCorrection,
+}
+
+/// The corrected artist.
+#[derive(Debug, Deserialize)]
+pub struct ArtistCorrection {
+    pub artist: ArtistRef,
+}
+
+/// Response wrapper from the API: `{ "similarartists": { ... } }`
+#[derive(Debug, Deserialize)]
+pub struct ArtistGetSimilarResponse {
+    pub similarartists: SimilarArtists,
+}
+
+/// A list of artists similar to the requested one.
+#[derive(Debug, Deserialize)]
+pub struct SimilarArtists {
+    #[serde(default, deserialize_with = "one_or_many")]
+    pub artist: Vec<SimilarArtist>,
+}
+
+/// A single similar artist object.
+#[derive(Debug, Deserialize)]
+pub struct SimilarArtist {
+    pub name: String,
+    pub mbid: Option<String>,
+    pub url: String,
+
+    /// Similarity score from 0.0 to 1.0.
+    #[serde(rename = "match", deserialize_with = "from_str")]
+    pub match_score: f64,
+
+    #[serde(default)]
+    pub image: Vec<Image>,
+}
+
+/// Response wrapper from the API: `{ "tags": { ... } }`
+#[derive(Debug, Deserialize)]
+pub struct ArtistGetTagsResponse {
+    #[serde(deserialize_with = "blank_as_default")]
+    pub tags: Tags,
+}
+
+/// Response wrapper from the API: `{ "topalbums": { ... } }`
+#[derive(Debug, Deserialize)]
+pub struct ArtistGetTopAlbumsResponse {
+    pub topalbums: ArtistTopAlbums,
+}
+
+/// A list of an artist's top albums and pagination info.
+#[derive(Debug, Deserialize)]
+pub struct ArtistTopAlbums {
+    #[serde(rename = "@attr")]
+    pub attr: PaginationMeta,
+
+    #[serde(default, deserialize_with = "one_or_many")]
+    pub album: Vec<ArtistTopAlbum>,
+}
+
+/// A single top album object.
+#[derive(Debug, Deserialize)]
+pub struct ArtistTopAlbum {
+    pub name: String,
+    pub mbid: Option<String>,
+    pub url: String,
+
+    #[serde(deserialize_with = "from_str")]
+    pub playcount: u64,
+
+    pub artist: ArtistRef,
+
+    #[serde(default)]
+    pub image: Vec<Image>,
+}
+
+/// Response wrapper from the API: `{ "toptags": { ... } }`
+#[derive(Debug, Deserialize)]
+pub struct ArtistGetTopTagsResponse {
+    pub toptags: ArtistTopTags,
+}
+
+/// The most popular tags applied to an artist.
+pub type ArtistTopTags = TopTags;
+
+/// Response wrapper from the API: `{ "toptracks": { ... } }`
+#[derive(Debug, Deserialize)]
+pub struct ArtistGetTopTracksResponse {
+    pub toptracks: ArtistTopTracks,
+}
+
+/// A list of an artist's top tracks and pagination info.
+#[derive(Debug, Deserialize)]
+pub struct ArtistTopTracks {
+    #[serde(rename = "@attr")]
+    pub attr: PaginationMeta,
+
+    #[serde(default, deserialize_with = "one_or_many")]
+    pub track: Vec<ArtistTopTrack>,
+}
+
+/// A single top track object.
+#[derive(Debug, Deserialize)]
+pub struct ArtistTopTrack {
+    pub name: String,
+    pub mbid: Option<String>,
+    pub url: String,
+
+    #[serde(deserialize_with = "from_str")]
+    pub playcount: u64,
+
+    #[serde(deserialize_with = "from_str")]
+    pub listeners: u64,
+
+    pub artist: ArtistRef,
+
+    #[serde(default)]
+    pub image: Vec<Image>,
+
+    #[serde(rename = "@attr")]
+    pub attr: RankAttr,
+}
+
+/// Response wrapper from the API: `{ "results": { ... } }`
+#[derive(Debug, Deserialize)]
+pub struct ArtistSearchResponse {
+    pub results: ArtistSearchResults,
+}
+
+/// Results of an `artist.search` call.
+#[derive(Debug, Deserialize)]
+pub struct ArtistSearchResults {
+    #[serde(flatten)]
+    pub meta: SearchMeta,
+
+    pub artistmatches: ArtistMatches,
+}
+
+/// The artists matching a search.
+#[derive(Debug, Deserialize)]
+pub struct ArtistMatches {
+    #[serde(default, deserialize_with = "one_or_many")]
+    pub artist: Vec<ArtistMatch>,
+}
+
+/// A single artist search result.
+#[derive(Debug, Deserialize)]
+pub struct ArtistMatch {
+    pub name: String,
+    pub mbid: Option<String>,
+    pub url: String,
+
+    #[serde(deserialize_with = "from_str")]
+    pub listeners: u64,
+
+    #[serde(default)]
+    pub image: Vec<Image>,
+}
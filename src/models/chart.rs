@@ -0,0 +1,109 @@
+//! Models for chart-related Last.fm API responses.
+
+use serde::Deserialize;
+
+use crate::models::common::{ArtistRef, Image, PaginationMeta};
+use crate::utils::{from_str, from_str_opt, one_or_many};
+
+/// Response wrapper from the API: `{ "artists": { ... } }`
+#[derive(Debug, Deserialize)]
+pub struct ChartGetTopArtistsResponse {
+    pub artists: ChartTopArtists,
+}
+
+/// The global top artists chart and pagination info.
+#[derive(Debug, Deserialize)]
+pub struct ChartTopArtists {
+    #[serde(rename = "@attr")]
+    pub attr: PaginationMeta,
+
+    #[serde(default, deserialize_with = "one_or_many")]
+    pub artist: Vec<ChartArtist>,
+}
+
+/// A single artist in the global chart.
+#[derive(Debug, Deserialize)]
+pub struct ChartArtist {
+    pub name: String,
+    pub mbid: Option<String>,
+    pub url: String,
+
+    #[serde(deserialize_with = "from_str")]
+    pub playcount: u64,
+
+    #[serde(deserialize_with = "from_str")]
+    pub listeners: u64,
+
+    #[serde(default)]
+    pub image: Vec<Image>,
+}
+
+/// Response wrapper from the API: `{ "tags": { ... } }`
+#[derive(Debug, Deserialize)]
+pub struct ChartGetTopTagsResponse {
+    pub tags: ChartTopTags,
+}
+
+/// The global top tags chart and pagination info.
+#[derive(Debug, Deserialize)]
+pub struct ChartTopTags {
+    #[serde(rename = "@attr")]
+    pub attr: PaginationMeta,
+
+    #[serde(default, deserialize_with = "one_or_many")]
+    pub tag: Vec<ChartTag>,
+}
+
+/// A single tag in the global chart.
+#[derive(Debug, Deserialize)]
+pub struct ChartTag {
+    pub name: String,
+    pub url: String,
+
+    /// Number of users that have used the tag.
+    #[serde(deserialize_with = "from_str")]
+    pub reach: u64,
+
+    /// Number of times the tag has been applied.
+    #[serde(deserialize_with = "from_str")]
+    pub taggings: u64,
+}
+
+/// Response wrapper from the API: `{ "tracks": { ... } }`
+#[derive(Debug, Deserialize)]
+pub struct ChartGetTopTracksResponse {
+    pub tracks: ChartTopTracks,
+}
+
+/// The global top tracks chart and pagination info.
+#[derive(Debug, Deserialize)]
+pub struct ChartTopTracks {
+    #[serde(rename = "@attr")]
+    pub attr: PaginationMeta,
+
+    #[serde(default, deserialize_with = "one_or_many")]
+    pub track: Vec<ChartTrack>,
+}
+
+/// A single track in the global chart.
+#[derive(Debug, Deserialize)]
+pub struct ChartTrack {
+    pub name: String,
+    pub mbid: Option<String>,
+    pub url: String,
+
+    /// Length of the track in seconds, `0` if unknown.
+    #[serde(default, deserialize_with = "from_str_opt")]
+    pub duration: Option<u32>,
+
+    #[serde(deserialize_with = "from_str")]
+    pub playcount: u64,
+
+    #[serde(deserialize_with = "from_str")]
+    pub listeners: u64,
+
+    pub artist: ArtistRef,
+
+    #[serde(default)]
+    pub image: Vec<Image>,
+}
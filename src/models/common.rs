@@ -1,8 +1,9 @@
 //! Contains common structs and traits used across the Last.fm API models.
 
+use chrono::{DateTime, Utc};
 use serde::Deserialize;
 
-use crate::utils::from_str;
+use crate::utils::{datetime_from_str, from_str, from_str_opt, one_or_many};
 
 /// Image with Last.fm `size` key
 #[derive(Debug, Deserialize)]
@@ -13,9 +14,15 @@ pub struct Image {
 }
 
 /// Meta information for paginated responses.
+///
+/// The page counters are always present. Which of `user`, `artist`, `country` or `tag` is set
+/// depends on what the list belongs to; global lists such as charts set none of them.
 #[derive(Debug, Deserialize)]
 pub struct PaginationMeta {
-    pub user: String,
+    pub user: Option<String>,
+    pub artist: Option<String>,
+    pub country: Option<String>,
+    pub tag: Option<String>,
 
     #[serde(rename = "totalPages", deserialize_with = "from_str")]
     pub total_pages: u32,
@@ -29,3 +36,118 @@ pub struct PaginationMeta {
     #[serde(deserialize_with = "from_str")]
     pub total: u32,
 }
+
+/// The `@attr` of a ranked list item.
+#[derive(Debug, Deserialize)]
+pub struct RankAttr {
+    #[serde(deserialize_with = "from_str")]
+    pub rank: u32,
+}
+
+/// OpenSearch meta information returned by `*.search` methods.
+#[derive(Debug, Deserialize)]
+pub struct SearchMeta {
+    #[serde(rename = "opensearch:Query")]
+    pub query: SearchQuery,
+
+    #[serde(rename = "opensearch:totalResults", deserialize_with = "from_str")]
+    pub total_results: u32,
+
+    #[serde(rename = "opensearch:startIndex", deserialize_with = "from_str")]
+    pub start_index: u32,
+
+    #[serde(rename = "opensearch:itemsPerPage", deserialize_with = "from_str")]
+    pub items_per_page: u32,
+}
+
+/// The query a search response was produced for.
+#[derive(Debug, Deserialize)]
+pub struct SearchQuery {
+    #[serde(rename = "searchTerms")]
+    pub search_terms: Option<String>,
+
+    #[serde(rename = "startPage", deserialize_with = "from_str")]
+    pub start_page: u32,
+}
+
+/// A minimal artist reference embedded in other objects.
+#[derive(Debug, Deserialize)]
+pub struct ArtistRef {
+    pub name: String,
+    pub mbid: Option<String>,
+    pub url: Option<String>,
+}
+
+/// A tag applied to an item.
+#[derive(Debug, Deserialize)]
+pub struct Tag {
+    pub name: String,
+    pub url: String,
+}
+
+/// A list of tags, e.g. `{ "tag": [ ... ] }`.
+///
+/// Last.fm sends a bare object for a single tag and omits the list entirely when there
+/// are none, so both cases are normalized here.
+#[derive(Debug, Default, Deserialize)]
+pub struct Tags {
+    #[serde(default, deserialize_with = "one_or_many")]
+    pub tag: Vec<Tag>,
+}
+
+/// A tag with its weight, as returned by `*.getTopTags`.
+#[derive(Debug, Deserialize)]
+pub struct TopTag {
+    pub name: String,
+    pub url: String,
+
+    /// Relative weight, from 0 to 100.
+    #[serde(deserialize_with = "from_str")]
+    pub count: u32,
+}
+
+/// A list of tags with their weights, e.g. `{ "tag": [ ... ] }`.
+#[derive(Debug, Default, Deserialize)]
+pub struct TopTags {
+    #[serde(default, deserialize_with = "one_or_many")]
+    pub tag: Vec<TopTag>,
+}
+
+/// Biography or wiki text attached to an artist, album, track or tag.
+#[derive(Debug, Deserialize)]
+pub struct Wiki {
+    pub published: Option<String>,
+    pub summary: String,
+    pub content: String,
+}
+
+/// Listener statistics.
+#[derive(Debug, Deserialize)]
+pub struct Stats {
+    #[serde(deserialize_with = "from_str")]
+    pub listeners: u64,
+
+    #[serde(deserialize_with = "from_str")]
+    pub playcount: u64,
+
+    /// Only present when a `username` was given.
+    #[serde(default, deserialize_with = "from_str_opt")]
+    pub userplaycount: Option<u64>,
+}
+
+/// The date range covered by a weekly chart.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Deserialize)]
+pub struct ChartRange {
+    #[serde(deserialize_with = "datetime_from_str")]
+    pub from: DateTime<Utc>,
+
+    #[serde(deserialize_with = "datetime_from_str")]
+    pub to: DateTime<Utc>,
+}
+
+/// A list of available weekly chart ranges, e.g. `{ "chart": [ ... ] }`.
+#[derive(Debug, Default, Deserialize)]
+pub struct WeeklyChartList {
+    #[serde(default, deserialize_with = "one_or_many")]
+    pub chart: Vec<ChartRange>,
+}
@@ -0,0 +1,76 @@
+//! Models for geo-related Last.fm API responses.
+
+use serde::Deserialize;
+
+use crate::models::common::{ArtistRef, Image, PaginationMeta, RankAttr};
+use crate::utils::{from_str, from_str_opt, one_or_many};
+
+/// Response wrapper from the API: `{ "topartists": { ... } }`
+#[derive(Debug, Deserialize)]
+pub struct GeoGetTopArtistsResponse {
+    pub topartists: GeoTopArtists,
+}
+
+/// The most popular artists in a country and pagination info.
+#[derive(Debug, Deserialize)]
+pub struct GeoTopArtists {
+    #[serde(rename = "@attr")]
+    pub attr: PaginationMeta,
+
+    #[serde(default, deserialize_with = "one_or_many")]
+    pub artist: Vec<GeoArtist>,
+}
+
+/// A single artist in a country chart.
+#[derive(Debug, Deserialize)]
+pub struct GeoArtist {
+    pub name: String,
+    pub mbid: Option<String>,
+    pub url: String,
+
+    #[serde(deserialize_with = "from_str")]
+    pub listeners: u64,
+
+    #[serde(default)]
+    pub image: Vec<Image>,
+}
+
+/// Response wrapper from the API: `{ "tracks": { ... } }`
+#[derive(Debug, Deserialize)]
+pub struct GeoGetTopTracksResponse {
+    pub tracks: GeoTopTracks,
+}
+
+/// The most popular tracks in a country and pagination info.
+#[derive(Debug, Deserialize)]
+pub struct GeoTopTracks {
+    #[serde(rename = "@attr")]
+    pub attr: PaginationMeta,
+
+    #[serde(default, deserialize_with = "one_or_many")]
+    pub track: Vec<GeoTrack>,
+}
+
+/// A single track in a country chart.
+#[derive(Debug, Deserialize)]
+pub struct GeoTrack {
+    pub name: String,
+    pub mbid: Option<String>,
+    pub url: String,
+
+    /// Length of the track in seconds, `0` if unknown.
+    #[serde(default, deserialize_with = "from_str_opt")]
+    pub duration: Option<u32>,
+
+    #[serde(deserialize_with = "from_str")]
+    pub listeners: u64,
+
+    pub artist: ArtistRef,
+
+    #[serde(default)]
+    pub image: Vec<Image>,
+
+    /// Position in the chart, starting at 0.
+    #[serde(rename = "@attr")]
+    pub attr: RankAttr,
+}
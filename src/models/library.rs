@@ -0,0 +1,44 @@
+//! Models for library-related Last.fm API responses.
+
+use serde::Deserialize;
+
+use crate::models::common::{Image, PaginationMeta};
+use crate::utils::{from_str, from_str_bool, one_or_many};
+
+/// Response wrapper from the API: `{ "artists": { ... } }`
+#[derive(Debug, Deserialize)]
+pub struct LibraryGetArtistsResponse {
+    pub artists: LibraryArtists,
+}
+
+/// A list of artists in a user's library and pagination info.
+#[derive(Debug, Deserialize)]
+pub struct LibraryArtists {
+    #[serde(rename = "@attr")]
+    pub attr: PaginationMeta,
+
+    #[serde(default, deserialize_with = "one_or_many")]
+    pub artist: Vec<LibraryArtist>,
+}
+
+/// A single artist in a user's library.
+#[derive(Debug, Deserialize)]
+pub struct LibraryArtist {
+    pub name: String,
+    pub mbid: Option<String>,
+    pub url: String,
+
+    /// Number of times the user has played this artist.
+    #[serde(deserialize_with = "from_str")]
+    pub playcount: u64,
+
+    /// Number of tags the user has applied to this artist.
+    #[serde(deserialize_with = "from_str")]
+    pub tagcount: u32,
+
+    #[serde(default)]
+    pub image: Vec<Image>,
+
+    #[serde(deserialize_with = "from_str_bool")]
+    pub streamable: bool,
+}
@@ -1,4 +1,11 @@
 //! This module contains all deserializable models used to interact with the Last.fm API.
 
+pub mod album;
+pub mod artist;
+pub mod chart;
 pub mod common;
+pub mod geo;
+pub mod library;
+pub mod tag;
+pub mod track;
 pub mod user;
@@ -0,0 +1,184 @@
+//! Models for tag-related Last.fm API responses.
+
+use serde::Deserialize;
+
+use crate::models::common::{
+    ArtistRef, Image, PaginationMeta, RankAttr, Tags, WeeklyChartList, Wiki,
+};
+use crate::utils::{from_str, from_str_opt, one_or_many};
+
+/// Response wrapper from the API: `{ "tag": { ... } }`
+#[derive(Debug, Deserialize)]
+pub struct TagGetInfoResponse {
+    pub tag: TagInfo,
+}
+
+/// Main tag info object returned by `tag.getInfo`
+#[derive(Debug, Deserialize)]
+pub struct TagInfo {
+    pub name: String,
+
+    /// Number of times the tag has been applied.
+    #[serde(deserialize_with = "from_str")]
+    pub total: u64,
+
+    /// Number of users that have used the tag.
+    #[serde(deserialize_with = "from_str")]
+    pub reach: u64,
+
+    pub wiki: Option<Wiki>,
+}
+
+/// Response wrapper from the API: `{ "similartags": { ... } }`
+#[derive(Debug, Deserialize)]
+pub struct TagGetSimilarResponse {
+    pub similartags: Tags,
+}
+
+/// Response wrapper from the API: `{ "albums": { ... } }`
+#[derive(Debug, Deserialize)]
+pub struct TagGetTopAlbumsResponse {
+    pub albums: TagTopAlbums,
+}
+
+/// The top albums for a tag and pagination info.
+#[derive(Debug, Deserialize)]
+pub struct TagTopAlbums {
+    #[serde(rename = "@attr")]
+    pub attr: PaginationMeta,
+
+    #[serde(default, deserialize_with = "one_or_many")]
+    pub album: Vec<TagTopAlbum>,
+}
+
+/// A single top album for a tag.
+#[derive(Debug, Deserialize)]
+pub struct TagTopAlbum {
+    pub name: String,
+    pub mbid: Option<String>,
+    pub url: String,
+    pub artist: ArtistRef,
+
+    #[serde(default)]
+    pub image: Vec<Image>,
+
+    #[serde(rename = "@attr")]
+    pub attr: RankAttr,
+}
+
+/// Response wrapper from the API: `{ "topartists": { ... } }`
+#[derive(Debug, Deserialize)]
+pub struct TagGetTopArtistsResponse {
+    pub topartists: TagTopArtists,
+}
+
+/// The top artists for a tag and pagination info.
+#[derive(Debug, Deserialize)]
+pub struct TagTopArtists {
+    #[serde(rename = "@attr")]
+    pub attr: PaginationMeta,
+
+    #[serde(default, deserialize_with = "one_or_many")]
+    pub artist: Vec<TagTopArtist>,
+}
+
+/// A single top artist for a tag.
+#[derive(Debug, Deserialize)]
+pub struct TagTopArtist {
+    pub name: String,
+    pub mbid: Option<String>,
+    pub url: String,
+
+    #[serde(default)]
+    pub image: Vec<Image>,
+
+    #[serde(rename = "@attr")]
+    pub attr: RankAttr,
+}
+
+/// Response wrapper from the API: `{ "toptags": { ... } }`
+#[derive(Debug, Deserialize)]
+pub struct TagGetTopTagsResponse {
+    pub toptags: TagTopTags,
+}
+
+/// The most used tags across Last.fm.
+///
+/// Unlike other lists this one is paged by offset rather than by page number.
+#[derive(Debug, Deserialize)]
+pub struct TagTopTags {
+    #[serde(rename = "@attr")]
+    pub attr: TagTopTagsMeta,
+
+    #[serde(default, deserialize_with = "one_or_many")]
+    pub tag: Vec<GlobalTag>,
+}
+
+/// Offset-based meta information for [`TagTopTags`].
+#[derive(Debug, Deserialize)]
+pub struct TagTopTagsMeta {
+    #[serde(deserialize_with = "from_str")]
+    pub offset: u32,
+
+    #[serde(deserialize_with = "from_str")]
+    pub num_res: u32,
+
+    #[serde(deserialize_with = "from_str")]
+    pub total: u32,
+}
+
+/// A tag with its global usage counts.
+#[derive(Debug, Deserialize)]
+pub struct GlobalTag {
+    pub name: String,
+
+    /// Number of times the tag has been applied.
+    #[serde(deserialize_with = "from_str")]
+    pub count: u64,
+
+    /// Number of users that have used the tag.
+    #[serde(deserialize_with = "from_str")]
+    pub reach: u64,
+}
+
+/// Response wrapper from the API: `{ "tracks": { ... } }`
+#[derive(Debug, Deserialize)]
+pub struct TagGetTopTracksResponse {
+    pub tracks: TagTopTracks,
+}
+
+/// The top tracks for a tag and pagination info.
+#[derive(Debug, Deserialize)]
+pub struct TagTopTracks {
+    #[serde(rename = "@attr")]
+    pub attr: PaginationMeta,
+
+    #[serde(default, deserialize_with = "one_or_many")]
+    pub track: Vec<TagTopTrack>,
+}
+
+/// A single top track for a tag.
+#[derive(Debug, Deserialize)]
+pub struct TagTopTrack {
+    pub name: String,
+    pub mbid: Option<String>,
+    pub url: String,
+
+    /// Length of the track in seconds, `0` if unknown.
+    #[serde(default, deserialize_with = "from_str_opt")]
+    pub duration: Option<u32>,
+
+    pub artist: ArtistRef,
+
+    #[serde(default)]
+    pub image: Vec<Image>,
+
+    #[serde(rename = "@attr")]
+    pub attr: RankAttr,
+}
+
+/// Response wrapper from the API: `{ "weeklychartlist": { ... } }`
+#[derive(Debug, Deserialize)]
+pub struct TagGetWeeklyChartListResponse {
+    pub weeklychartlist: WeeklyChartList,
+}
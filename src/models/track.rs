@@ -0,0 +1,328 @@
+//! Models for track-related Last.fm API responses.
+
+use std::str::FromStr;
+
+use serde::Deserialize;
+
+use crate::models::common::{ArtistRef, Image, SearchMeta, Tags, TopTags, Wiki};
+use crate::models::user::Streamable;
+use crate::utils::{
+    blank_as_default, from_str, from_str_bool, from_str_bool_opt, from_str_opt, one_or_many,
+};
+
+/// Response wrapper for scrobbles: `{ "scrobbles": { ... } }`
+#[derive(Debug, Deserialize)]
+pub struct TrackScrobbleResponse {
+    pub scrobbles: Scrobbles,
+}
+
+/// The outcome of a `track.scrobble` call.
+#[derive(Debug, Deserialize)]
+pub struct Scrobbles {
+    #[serde(rename = "@attr")]
+    pub attr: ScrobblesMeta,
+
+    /// One entry per submitted scrobble, in submission order.
+    #[serde(rename = "scrobble", deserialize_with = "one_or_many")]
+    pub scrobbles: Vec<ScrobbleResult>,
+}
+
+/// Number of accepted and ignored scrobbles in a batch.
+#[derive(Debug, Deserialize)]
+pub struct ScrobblesMeta {
+    pub accepted: u32,
+    pub ignored: u32,
+}
+
+/// The result for a single submitted scrobble.
+#[derive(Debug, Deserialize)]
+pub struct ScrobbleResult {
+    pub artist: CorrectedText,
+    pub track: CorrectedText,
+    pub album: CorrectedText,
+
+    #[serde(rename = "albumArtist")]
+    pub album_artist: CorrectedText,
+
+    #[serde(deserialize_with = "from_str")]
+    pub timestamp: i64,
+
+    #[serde(rename = "ignoredMessage")]
+    pub ignored_message: IgnoredMessage,
+}
+
+impl ScrobbleResult {
+    /// Whether Last.fm accepted this scrobble.
+    pub fn is_accepted(&self) -> bool {
+        self.ignored_message.code == IgnoredCode::NotIgnored
+    }
+}
+
+/// Response wrapper for now-playing updates: `{ "nowplaying": { ... } }`
+#[derive(Debug, Deserialize)]
+pub struct TrackUpdateNowPlayingResponse {
+    pub nowplaying: NowPlayingResult,
+}
+
+/// The outcome of a `track.updateNowPlaying` call.
+#[derive(Debug, Deserialize)]
+pub struct NowPlayingResult {
+    pub artist: CorrectedText,
+    pub track: CorrectedText,
+    pub album: CorrectedText,
+
+    #[serde(rename = "albumArtist")]
+    pub album_artist: CorrectedText,
+
+    #[serde(rename = "ignoredMessage")]
+    pub ignored_message: IgnoredMessage,
+}
+
+impl NowPlayingResult {
+    /// Whether Last.fm accepted the now-playing update.
+    pub fn is_accepted(&self) -> bool {
+        self.ignored_message.code == IgnoredCode::NotIgnored
+    }
+}
+
+/// A value echoed back by Last.fm, possibly after auto-correction.
+#[derive(Debug, Deserialize)]
+pub struct CorrectedText {
+    /// `true` if Last.fm corrected the submitted value.
+    #[serde(deserialize_with = "from_str_bool")]
+    pub corrected: bool,
+
+    #[serde(rename = "#text", default)]
+    pub text: String,
+}
+
+/// Why Last.fm ignored a scrobble or now-playing update, if it did.
+#[derive(Debug, Deserialize)]
+pub struct IgnoredMessage {
+    #[serde(deserialize_with = "from_str")]
+    pub code: IgnoredCode,
+
+    #[serde(rename = "#text", default)]
+    pub message: String,
+}
+
+/// Ignored-message codes.
+///
+/// See the "Ignored messages" section of the [scrobbling docs](https://www.last.fm/api/scrobbling).
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum IgnoredCode {
+    /// The item was not ignored.
+    NotIgnored,
+    /// The artist was ignored.
+    ArtistIgnored,
+    /// The track was ignored.
+    TrackIgnored,
+    /// The timestamp was too old.
+    TimestampTooOld,
+    /// The timestamp was too new.
+    TimestampTooNew,
+    /// The daily scrobble limit was exceeded.
+    DailyScrobbleLimitExceeded,
+    /// A code not documented at the time of writing.
+    Other(u8),
+}
+
+impl FromStr for IgnoredCode {
+    type Err = std::num::ParseIntError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s.parse::<u8>()? {
+            0 => Self::NotIgnored,
+            1 => Self::ArtistIgnored,
+            2 => Self::TrackIgnored,
+            3 => Self::TimestampTooOld,
+            4 => Self::TimestampTooNew,
+            5 => Self::DailyScrobbleLimitExceeded,
+            code => Self::Other(code),
+        })
+    }
+}
+
+/// Response wrapper from the API: `{ "track": { ... } }`
+#[derive(Debug, Deserialize)]
+pub struct TrackGetInfoResponse {
+    pub track: TrackInfo,
+}
+
+/// Main track info object returned by `track.getInfo`
+#[derive(Debug, Deserialize)]
+pub struct TrackInfo {
+    pub name: String,
+    pub mbid: Option<String>,
+    pub url: String,
+
+    /// Length of the track in milliseconds, `0` if unknown.
+    #[serde(default, deserialize_with = "from_str_opt")]
+    pub duration: Option<u64>,
+
+    pub streamable: Option<Streamable>,
+
+    #[serde(deserialize_with = "from_str")]
+    pub listeners: u64,
+
+    #[serde(deserialize_with = "from_str")]
+    pub playcount: u64,
+
+    pub artist: ArtistRef,
+    pub album: Option<TrackAlbum>,
+
+    /// Only present when a `username` was given.
+    #[serde(default, deserialize_with = "from_str_opt")]
+    pub userplaycount: Option<u64>,
+
+    /// Only present when a `username` was given.
+    #[serde(default, deserialize_with = "from_str_bool_opt")]
+    pub userloved: Option<bool>,
+
+    #[serde(default, deserialize_with = "blank_as_default")]
+    pub toptags: Tags,
+
+    pub wiki: Option<Wiki>,
+}
+
+/// The album a track appears on.
+#[derive(Debug, Deserialize)]
+pub struct TrackAlbum {
+    pub title: String,
+    pub artist: String,
+    pub mbid: Option<String>,
+    pub url: String,
+
+    #[serde(default)]
+    pub image: Vec<Image>,
+}
+
+/// Response wrapper from the API: `{ "corrections": { ... } }`
+#[derive(Debug, Deserialize)]
+pub struct TrackGetCorrectionResponse {
+    /// `None` if Last.fm has no correction for the track.
+    #[serde(deserialize_with = "blank_as_default")]
+    pub corrections: Option<TrackCorrections>,
+}
+
+/// Wrapper around the single correction returned by `track.getCorrection`.
+#[derive(Debug, Deserialize)]
+pub struct TrackCorrections {
+    pub correction: TrackCorrection,
+}
+
+/// The corrected track, and which parts of it were corrected.
+#[derive(Debug, Deserialize)]
+pub struct TrackCorrection {
+    pub track: CorrectedTrack,
+
+    #[serde(rename = "@attr")]
+    pub attr: TrackCorrectionAttr,
+}
+
+/// A corrected track reference.
+#[derive(Debug, Deserialize)]
+pub struct CorrectedTrack {
+    pub name: String,
+    pub mbid: Option<String>,
+    pub url: String,
+    pub artist: ArtistRef,
+}
+
+/// Flags telling which parts of a track were corrected.
+#[derive(Debug, Deserialize)]
+pub struct TrackCorrectionAttr {
+    #[serde(deserialize_with = "from_str_bool")]
+    pub artistcorrected: bool,
+
+    #[serde(deserialize_with = "from_str_bool")]
+    pub trackcorrected: bool,
+}
+
+/// Response wrapper from the API: `{ "similartracks": { ... } }`
+#[derive(Debug, Deserialize)]
+pub struct TrackGetSimilarResponse {
+    pub similartracks: SimilarTracks,
+}
+
+/// A list of tracks similar to the requested one.
+#[derive(Debug, Deserialize)]
+pub struct SimilarTracks {
+    #[serde(default, deserialize_with = "one_or_many")]
+    pub track: Vec<SimilarTrack>,
+}
+
+/// A single similar track object.
+#[derive(Debug, Deserialize)]
+pub struct SimilarTrack {
+    pub name: String,
+    pub mbid: Option<String>,
+    pub url: String,
+
+    /// Similarity score from 0.0 to 1.0.
+    #[serde(rename = "match", deserialize_with = "from_str")]
+    pub match_score: f64,
+
+    #[serde(default, deserialize_with = "from_str_opt")]
+    pub playcount: Option<u64>,
+
+    /// Length of the track in seconds, if known.
+    #[serde(default, deserialize_with = "from_str_opt")]
+    pub duration: Option<u32>,
+
+    pub artist: ArtistRef,
+
+    #[serde(default)]
+    pub image: Vec<Image>,
+}
+
+/// Response wrapper from the API: `{ "tags": { ... } }`
+#[derive(Debug, Deserialize)]
+pub struct TrackGetTagsResponse {
+    #[serde(deserialize_with = "blank_as_default")]
+    pub tags: Tags,
+}
+
+/// Response wrapper from the API: `{ "toptags": { ... } }`
+#[derive(Debug, Deserialize)]
+pub struct TrackGetTopTagsResponse {
+    #[serde(deserialize_with = "blank_as_default")]
+    pub toptags: TopTags,
+}
+
+/// Response wrapper from the API: `{ "results": { ... } }`
+#[derive(Debug, Deserialize)]
+pub struct TrackSearchResponse {
+    pub results: TrackSearchResults,
+}
+
+/// Results of a `track.search` call.
+#[derive(Debug, Deserialize)]
+pub struct TrackSearchResults {
+    #[serde(flatten)]
+    pub meta: SearchMeta,
+
+    pub trackmatches: TrackMatches,
+}
+
+/// The tracks matching a search.
+#[derive(Debug, Deserialize)]
+pub struct TrackMatches {
+    #[serde(default, deserialize_with = "one_or_many")]
+    pub track: Vec<TrackMatch>,
+}
+
+/// A single track search result.
+#[derive(Debug, Deserialize)]
+pub struct TrackMatch {
+    pub name: String,
+    pub artist: String,
+    pub mbid: Option<String>,
+    pub url: String,
+
+    #[serde(deserialize_with = "from_str")]
+    pub listeners: u64,
+
+    #[serde(default)]
+    pub image: Vec<Image>,
+}
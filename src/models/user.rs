@@ -1,9 +1,14 @@
 //! Models for user-related Last.fm API responses.
 
+use chrono::{DateTime, Utc};
 use serde::Deserialize;
 
-use crate::models::common::{Image, PaginationMeta};
-use crate::utils::{from_str, from_str_opt};
+use crate::models::common::{
+    ArtistRef, ChartRange, Image, PaginationMeta, RankAttr, TopTag, WeeklyChartList,
+};
+use crate::utils::{
+    datetime_from_str, from_str, from_str_bool, from_str_bool_opt, from_str_opt, one_or_many,
+};
 
 /// Response wrapper from the API: `{ "user": { ... } }`
 #[derive(Debug, Deserialize)]
@@ -154,3 +159,504 @@ pub struct Streamable {
     #[serde(rename = "#text", deserialize_with = "from_str")]
     pub is_streamable: u8,
 }
+
+/// Response wrapper for recent tracks: `{ "recenttracks": { ... } }`
+#[derive(Debug, Deserialize)]
+pub struct UserGetRecentTracksResponse {
+    pub recenttracks: RecentTracks,
+}
+
+/// A list of recent tracks and pagination info.
+///
+/// If the user is listening to something, the first entry is
+/// [`RecentTrack::NowPlaying`] and comes on top of the requested `limit`.
+#[derive(Debug, Deserialize)]
+pub struct RecentTracks {
+    #[serde(rename = "@attr")]
+    pub attr: PaginationMeta,
+
+    #[serde(default, deserialize_with = "one_or_many")]
+    pub track: Vec<RecentTrack>,
+}
+
+impl RecentTracks {
+    /// The track currently playing, if any.
+    pub fn now_playing(&self) -> Option<&RecentTrackInfo> {
+        self.track.iter().find_map(|track| match track {
+            RecentTrack::NowPlaying(info) => Some(info),
+            RecentTrack::Scrobbled { .. } => None,
+        })
+    }
+}
+
+/// An entry of `user.getRecentTracks`.
+#[derive(Debug, Deserialize)]
+#[serde(try_from = "RawRecentTrack")]
+pub enum RecentTrack {
+    /// The track the user is listening to right now. It has not been scrobbled yet.
+    NowPlaying(RecentTrackInfo),
+
+    /// A scrobbled track and the time it was played.
+    Scrobbled {
+        track: RecentTrackInfo,
+        date: DateTime<Utc>,
+    },
+}
+
+impl RecentTrack {
+    /// The track details, regardless of the variant.
+    pub fn track(&self) -> &RecentTrackInfo {
+        match self {
+            Self::NowPlaying(track) | Self::Scrobbled { track, .. } => track,
+        }
+    }
+
+    /// When the track was scrobbled; `None` while it is playing.
+    pub fn date(&self) -> Option<DateTime<Utc>> {
+        match self {
+            Self::NowPlaying(_) => None,
+            Self::Scrobbled { date, .. } => Some(*date),
+        }
+    }
+}
+
+/// Details of a recent track.
+#[derive(Debug, Deserialize)]
+pub struct RecentTrackInfo {
+    pub name: String,
+    pub url: String,
+    pub mbid: Option<String>,
+    pub artist: RecentTrackArtist,
+    pub album: RecentTrackAlbum,
+
+    #[serde(default)]
+    pub image: Vec<Image>,
+
+    #[serde(deserialize_with = "from_str_bool")]
+    pub streamable: bool,
+
+    /// Whether the user loved the track. Only set in extended mode.
+    #[serde(default, deserialize_with = "from_str_bool_opt")]
+    pub loved: Option<bool>,
+}
+
+/// Artist of a recent track.
+///
+/// In extended mode Last.fm sends the full artist object, which fills in `url` and `image`.
+#[derive(Debug, Deserialize)]
+pub struct RecentTrackArtist {
+    #[serde(alias = "#text")]
+    pub name: String,
+    pub mbid: Option<String>,
+    pub url: Option<String>,
+
+    #[serde(default)]
+    pub image: Vec<Image>,
+}
+
+/// Album of a recent track.
+#[derive(Debug, Deserialize)]
+pub struct RecentTrackAlbum {
+    #[serde(rename = "#text")]
+    pub name: String,
+    pub mbid: Option<String>,
+}
+
+/// The wire format of a recent track, before now-playing detection.
+#[derive(Deserialize)]
+struct RawRecentTrack {
+    #[serde(flatten)]
+    info: RecentTrackInfo,
+    date: Option<RecentTrackDate>,
+
+    #[serde(rename = "@attr")]
+    attr: Option<NowPlayingAttr>,
+}
+
+#[derive(Deserialize)]
+struct RecentTrackDate {
+    #[serde(deserialize_with = "datetime_from_str")]
+    uts: DateTime<Utc>,
+}
+
+#[derive(Deserialize)]
+struct NowPlayingAttr {
+    nowplaying: Option<String>,
+}
+
+impl TryFrom<RawRecentTrack> for RecentTrack {
+    type Error = String;
+
+    fn try_from(raw: RawRecentTrack) -> Result<Self, Self::Error> {
+        let now_playing = raw
+            .attr
+            .and_then(|attr| attr.nowplaying)
+            .is_some_and(|flag| flag == "true");
+
+        match raw.date {
+            Some(date) => Ok(Self::Scrobbled {
+                track: raw.info,
+                date: date.uts,
+            }),
+            None if now_playing => Ok(Self::NowPlaying(raw.info)),
+            None => Err(format!(
+                "recent track `{}` has neither a date nor a now-playing flag",
+                raw.info.name
+            )),
+        }
+    }
+}
+
+/// Response wrapper for top artists: `{ "topartists": { ... } }`
+#[derive(Debug, Deserialize)]
+pub struct UserGetTopArtistsResponse {
+    pub topartists: UserTopArtists,
+}
+
+/// A user's most played artists and pagination info.
+#[derive(Debug, Deserialize)]
+pub struct UserTopArtists {
+    #[serde(rename = "@attr")]
+    pub attr: PaginationMeta,
+
+    #[serde(default, deserialize_with = "one_or_many")]
+    pub artist: Vec<UserTopArtist>,
+}
+
+/// A single top artist object.
+#[derive(Debug, Deserialize)]
+pub struct UserTopArtist {
+    pub name: String,
+    pub mbid: Option<String>,
+    pub url: String,
+
+    #[serde(deserialize_with = "from_str")]
+    pub playcount: u64,
+
+    #[serde(default)]
+    pub image: Vec<Image>,
+
+    #[serde(rename = "@attr")]
+    pub attr: RankAttr,
+}
+
+/// Response wrapper for top albums: `{ "topalbums": { ... } }`
+#[derive(Debug, Deserialize)]
+pub struct UserGetTopAlbumsResponse {
+    pub topalbums: UserTopAlbums,
+}
+
+/// A user's most played albums and pagination info.
+#[derive(Debug, Deserialize)]
+pub struct UserTopAlbums {
+    #[serde(rename = "@attr")]
+    pub attr: PaginationMeta,
+
+    #[serde(default, deserialize_with = "one_or_many")]
+    pub album: Vec<UserTopAlbum>,
+}
+
+/// A single top album object.
+#[derive(Debug, Deserialize)]
+pub struct UserTopAlbum {
+    pub name: String,
+    pub mbid: Option<String>,
+    pub url: String,
+    pub artist: ArtistRef,
+
+    #[serde(deserialize_with = "from_str")]
+    pub playcount: u64,
+
+    #[serde(default)]
+    pub image: Vec<Image>,
+
+    #[serde(rename = "@attr")]
+    pub attr: RankAttr,
+}
+
+/// Response wrapper for top tracks: `{ "toptracks": { ... } }`
+#[derive(Debug, Deserialize)]
+pub struct UserGetTopTracksResponse {
+    pub toptracks: UserTopTracks,
+}
+
+/// A user's most played tracks and pagination info.
+#[derive(Debug, Deserialize)]
+pub struct UserTopTracks {
+    #[serde(rename = "@attr")]
+    pub attr: PaginationMeta,
+
+    #[serde(default, deserialize_with = "one_or_many")]
+    pub track: Vec<UserTopTrack>,
+}
+
+/// A single top track object.
+#[derive(Debug, Deserialize)]
+pub struct UserTopTrack {
+    pub name: String,
+    pub mbid: Option<String>,
+    pub url: String,
+    pub artist: ArtistRef,
+
+    #[serde(deserialize_with = "from_str")]
+    pub playcount: u64,
+
+    /// Length of the track in seconds, `0` if unknown.
+    #[serde(default, deserialize_with = "from_str_opt")]
+    pub duration: Option<u32>,
+
+    #[serde(default)]
+    pub image: Vec<Image>,
+
+    #[serde(rename = "@attr")]
+    pub attr: RankAttr,
+}
+
+/// Response wrapper for top tags: `{ "toptags": { ... } }`
+#[derive(Debug, Deserialize)]
+pub struct UserGetTopTagsResponse {
+    pub toptags: UserTopTags,
+}
+
+/// The tags a user applied most often.
+///
+/// Last.fm doesn't paginate this list, so only the user is reported.
+#[derive(Debug, Deserialize)]
+pub struct UserTopTags {
+    #[serde(rename = "@attr")]
+    pub attr: UserAttr,
+
+    #[serde(default, deserialize_with = "one_or_many")]
+    pub tag: Vec<TopTag>,
+}
+
+/// An `@attr` object that only names the user a list belongs to.
+#[derive(Debug, Deserialize)]
+pub struct UserAttr {
+    pub user: String,
+}
+
+/// Response wrapper for the weekly chart list: `{ "weeklychartlist": { ... } }`
+#[derive(Debug, Deserialize)]
+pub struct UserGetWeeklyChartListResponse {
+    pub weeklychartlist: WeeklyChartList,
+}
+
+/// The `@attr` of a weekly chart: who it belongs to and the week it covers.
+#[derive(Debug, Deserialize)]
+pub struct WeeklyChartAttr {
+    pub user: String,
+
+    #[serde(flatten)]
+    pub range: ChartRange,
+}
+
+/// Response wrapper for weekly artist charts: `{ "weeklyartistchart": { ... } }`
+#[derive(Debug, Deserialize)]
+pub struct UserGetWeeklyArtistChartResponse {
+    pub weeklyartistchart: WeeklyArtistChart,
+}
+
+/// The artists a user played during one week.
+#[derive(Debug, Deserialize)]
+pub struct WeeklyArtistChart {
+    #[serde(rename = "@attr")]
+    pub attr: WeeklyChartAttr,
+
+    #[serde(default, deserialize_with = "one_or_many")]
+    pub artist: Vec<WeeklyChartArtist>,
+}
+
+/// A single artist in a weekly chart.
+#[derive(Debug, Deserialize)]
+pub struct WeeklyChartArtist {
+    pub name: String,
+    pub mbid: Option<String>,
+    pub url: String,
+
+    #[serde(deserialize_with = "from_str")]
+    pub playcount: u64,
+
+    #[serde(rename = "@attr")]
+    pub attr: RankAttr,
+}
+
+/// Response wrapper for weekly album charts: `{ "weeklyalbumchart": { ... } }`
+#[derive(Debug, Deserialize)]
+pub struct UserGetWeeklyAlbumChartResponse {
+    pub weeklyalbumchart: WeeklyAlbumChart,
+}
+
+/// The albums a user played during one week.
+#[derive(Debug, Deserialize)]
+pub struct WeeklyAlbumChart {
+    #[serde(rename = "@attr")]
+    pub attr: WeeklyChartAttr,
+
+    #[serde(default, deserialize_with = "one_or_many")]
+    pub album: Vec<WeeklyChartAlbum>,
+}
+
+/// A single album in a weekly chart.
+#[derive(Debug, Deserialize)]
+pub struct WeeklyChartAlbum {
+    pub name: String,
+    pub mbid: Option<String>,
+    pub url: String,
+    pub artist: WeeklyChartItemArtist,
+
+    #[serde(deserialize_with = "from_str")]
+    pub playcount: u64,
+
+    #[serde(rename = "@attr")]
+    pub attr: RankAttr,
+}
+
+/// Response wrapper for weekly track charts: `{ "weeklytrackchart": { ... } }`
+#[derive(Debug, Deserialize)]
+pub struct UserGetWeeklyTrackChartResponse {
+    pub weeklytrackchart: WeeklyTrackChart,
+}
+
+/// The tracks a user played during one week.
+#[derive(Debug, Deserialize)]
+pub struct WeeklyTrackChart {
+    #[serde(rename = "@attr")]
+    pub attr: WeeklyChartAttr,
+
+    #[serde(default, deserialize_with = "one_or_many")]
+    pub track: Vec<WeeklyChartTrack>,
+}
+
+/// A single track in a weekly chart.
+#[derive(Debug, Deserialize)]
+pub struct WeeklyChartTrack {
+    pub name: String,
+    pub mbid: Option<String>,
+    pub url: String,
+    pub artist: WeeklyChartItemArtist,
+
+    #[serde(deserialize_with = "from_str")]
+    pub playcount: u64,
+
+    #[serde(default)]
+    pub image: Vec<Image>,
+
+    #[serde(rename = "@attr")]
+    pub attr: RankAttr,
+}
+
+/// Artist of an album or track in a weekly chart.
+#[derive(Debug, Deserialize)]
+pub struct WeeklyChartItemArtist {
+    #[serde(rename = "#text")]
+    pub name: String,
+    pub mbid: Option<String>,
+}
+
+/// Response wrapper for personal tags: `{ "taggings": { ... } }`
+#[derive(Debug, Deserialize)]
+pub struct UserGetPersonalTagsResponse {
+    pub taggings: PersonalTags,
+}
+
+/// Items a user tagged with a given tag, and pagination info.
+#[derive(Debug, Deserialize)]
+#[serde(try_from = "RawPersonalTags")]
+pub struct PersonalTags {
+    pub attr: PaginationMeta,
+    pub items: PersonalTagItems,
+}
+
+/// The tagged items, typed by the requested tagging type.
+#[derive(Debug)]
+pub enum PersonalTagItems {
+    Artists(Vec<PersonalTagArtist>),
+    Albums(Vec<PersonalTagAlbum>),
+    Tracks(Vec<PersonalTagTrack>),
+}
+
+/// An artist the user tagged.
+#[derive(Debug, Deserialize)]
+pub struct PersonalTagArtist {
+    pub name: String,
+    pub mbid: Option<String>,
+    pub url: String,
+
+    #[serde(default)]
+    pub image: Vec<Image>,
+}
+
+/// An album the user tagged.
+#[derive(Debug, Deserialize)]
+pub struct PersonalTagAlbum {
+    pub name: String,
+    pub mbid: Option<String>,
+    pub url: String,
+    pub artist: ArtistRef,
+
+    #[serde(default)]
+    pub image: Vec<Image>,
+}
+
+/// A track the user tagged.
+#[derive(Debug, Deserialize)]
+pub struct PersonalTagTrack {
+    pub name: String,
+    pub mbid: Option<String>,
+    pub url: String,
+    pub artist: ArtistRef,
+
+    /// Length of the track in seconds, `0` if unknown.
+    #[serde(default, deserialize_with = "from_str_opt")]
+    pub duration: Option<u32>,
+
+    #[serde(default)]
+    pub image: Vec<Image>,
+}
+
+/// The wire format of personal tags, where the list sits under a type-specific key.
+#[derive(Deserialize)]
+struct RawPersonalTags {
+    #[serde(rename = "@attr")]
+    attr: PaginationMeta,
+    artists: Option<RawTaggedArtists>,
+    albums: Option<RawTaggedAlbums>,
+    tracks: Option<RawTaggedTracks>,
+}
+
+#[derive(Deserialize)]
+struct RawTaggedArtists {
+    #[serde(default, deserialize_with = "one_or_many")]
+    artist: Vec<PersonalTagArtist>,
+}
+
+#[derive(Deserialize)]
+struct RawTaggedAlbums {
+    #[serde(default, deserialize_with = "one_or_many")]
+    album: Vec<PersonalTagAlbum>,
+}
+
+#[derive(Deserialize)]
+struct RawTaggedTracks {
+    #[serde(default, deserialize_with = "one_or_many")]
+    track: Vec<PersonalTagTrack>,
+}
+
+impl TryFrom<RawPersonalTags> for PersonalTags {
+    type Error = &'static str;
+
+    fn try_from(raw: RawPersonalTags) -> Result<Self, Self::Error> {
+        let items = match (raw.artists, raw.albums, raw.tracks) {
+            (Some(artists), None, None) => PersonalTagItems::Artists(artists.artist),
+            (None, Some(albums), None) => PersonalTagItems::Albums(albums.album),
+            (None, None, Some(tracks)) => PersonalTagItems::Tracks(tracks.track),
+            _ => return Err("expected exactly one of `artists`, `albums` or `tracks`"),
+        };
+
+        Ok(Self {
+            attr: raw.attr,
+            items,
+        })
+    }
+}
@@ -0,0 +1,341 @@
+//! Streaming over paginated Last.fm results.
+//!
+//! Any method returning a [`Paginated`] model can be turned into a stream of
+//! items with [`paginate`], which requests one page after another, or read in
+//! full with [`PageStream::collect_all`], which requests several pages at once.
+
+use std::collections::{HashSet, VecDeque};
+use std::future::Future;
+use std::hash::Hash;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use chrono::{DateTime, Utc};
+use futures::{Stream, StreamExt};
+
+use crate::error::Error;
+use crate::models::{
+    artist::{ArtistTopAlbum, ArtistTopAlbums, ArtistTopTrack, ArtistTopTracks},
+    chart::{ChartArtist, ChartTag, ChartTopArtists, ChartTopTags, ChartTopTracks, ChartTrack},
+    common::PaginationMeta,
+    geo::{GeoArtist, GeoTopArtists, GeoTopTracks, GeoTrack},
+    library::{LibraryArtist, LibraryArtists},
+    tag::{TagTopAlbum, TagTopAlbums, TagTopArtist, TagTopArtists, TagTopTrack, TagTopTracks},
+    user::{
+        Friend, LovedTrack, LovedTracks, RecentTrack, RecentTracks, UserFriends, UserTopAlbum,
+        UserTopAlbums, UserTopArtist, UserTopArtists, UserTopTrack, UserTopTracks,
+    },
+};
+
+/// A single page of a paginated Last.fm list.
+pub trait Paginated {
+    /// The type of the listed items.
+    type Item;
+
+    /// Identifies an item, so that one showing up on two pages can be dropped.
+    type Key: Eq + Hash;
+
+    /// The pagination info of this page.
+    fn pagination(&self) -> &PaginationMeta;
+
+    /// The items on this page, in order.
+    fn into_items(self) -> Vec<Self::Item>;
+
+    /// The key of `item`.
+    fn item_key(item: &Self::Item) -> Self::Key;
+}
+
+macro_rules! impl_paginated {
+    ($($ty:ty => $field:ident: $item:ty,)*) => {
+        $(
+            impl Paginated for $ty {
+                type Item = $item;
+                type Key = String;
+
+                fn pagination(&self) -> &PaginationMeta {
+                    &self.attr
+                }
+
+                fn into_items(self) -> Vec<Self::Item> {
+                    self.$field
+                }
+
+                fn item_key(item: &Self::Item) -> String {
+                    item.url.clone()
+                }
+            }
+        )*
+    };
+}
+
+impl_paginated! {
+    ArtistTopAlbums => album: ArtistTopAlbum,
+    ArtistTopTracks => track: ArtistTopTrack,
+    ChartTopArtists => artist: ChartArtist,
+    ChartTopTags => tag: ChartTag,
+    ChartTopTracks => track: ChartTrack,
+    GeoTopArtists => artist: GeoArtist,
+    GeoTopTracks => track: GeoTrack,
+    LibraryArtists => artist: LibraryArtist,
+    TagTopAlbums => album: TagTopAlbum,
+    TagTopArtists => artist: TagTopArtist,
+    TagTopTracks => track: TagTopTrack,
+    UserFriends => user: Friend,
+    LovedTracks => track: LovedTrack,
+    UserTopAlbums => album: UserTopAlbum,
+    UserTopArtists => artist: UserTopArtist,
+    UserTopTracks => track: UserTopTrack,
+}
+
+/// The same track can be scrobbled many times, so plays are told apart by time.
+impl Paginated for RecentTracks {
+    type Item = RecentTrack;
+    type Key = (String, Option<DateTime<Utc>>);
+
+    fn pagination(&self) -> &PaginationMeta {
+        &self.attr
+    }
+
+    fn into_items(self) -> Vec<Self::Item> {
+        self.track
+    }
+
+    fn item_key(item: &Self::Item) -> Self::Key {
+        (item.track().url.clone(), item.date())
+    }
+}
+
+/// Stream the items of every page returned by `fetch`, starting at page 1.
+///
+/// `fetch` is called with the page number to request. While the items of one page
+/// are consumed, the next page is already being fetched. The stream ends after the
+/// last page, or after the first error.
+///
+/// Items already yielded from an earlier page are skipped. This drops the
+/// now-playing entry that Last.fm repeats at the top of every page of recent tracks,
+/// as well as items that moved to the next page because the list shifted.
+///
+/// # Example
+///
+/// ```no_run
+/// use futures::StreamExt;
+/// use soniq::client::Client;
+/// use soniq::paginate::paginate;
+///
+/// # async fn run(client: Client) -> Result<(), soniq::Error> {
+/// let client = &client;
+/// let mut tracks = paginate(move |page| async move {
+///     client
+///         .user()
+///         .get_loved_tracks_page("rj", Some(200), Some(page))
+///         .await
+/// })
+/// .max_items(500);
+///
+/// while let Some(track) = tracks.next().await {
+///     println!("{}", track?.name);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub fn paginate<F, Fut, P>(fetch: F) -> PageStream<F, Fut, P>
+where
+    F: FnMut(u32) -> Fut,
+    Fut: Future<Output = Result<P, Error>>,
+    P: Paginated,
+{
+    PageStream {
+        fetch,
+        next_page: 1,
+        max_items: None,
+        pending: None,
+        fetched: None,
+        buffer: VecDeque::new(),
+        seen: HashSet::new(),
+        yielded: 0,
+        done: false,
+    }
+}
+
+/// A stream over the items of a paginated list, created by [`paginate`].
+pub struct PageStream<F, Fut, P: Paginated> {
+    fetch: F,
+    next_page: u32,
+    max_items: Option<usize>,
+    /// The page currently being requested.
+    pending: Option<(u32, Pin<Box<Fut>>)>,
+    /// A page that arrived while items of the previous one were still buffered.
+    fetched: Option<(u32, Result<P, Error>)>,
+    buffer: VecDeque<P::Item>,
+    /// Keys of the items buffered so far.
+    seen: HashSet<P::Key>,
+    yielded: usize,
+    done: bool,
+}
+
+// No field is ever pinned structurally; the request future is boxed.
+impl<F, Fut, P: Paginated> Unpin for PageStream<F, Fut, P> {}
+
+impl<F, Fut, P> PageStream<F, Fut, P>
+where
+    F: FnMut(u32) -> Fut,
+    Fut: Future<Output = Result<P, Error>>,
+    P: Paginated,
+{
+    /// Start at `page` instead of page 1.
+    pub fn start_page(mut self, page: u32) -> Self {
+        self.next_page = page.max(1);
+        self
+    }
+
+    /// Stop after `max` items, without requesting pages beyond them.
+    pub fn max_items(mut self, max: usize) -> Self {
+        self.max_items = Some(max);
+        self
+    }
+
+    /// Fetch every page and return all items in order, instead of streaming them.
+    ///
+    /// The first page is requested on its own to learn `total_pages`; the rest are
+    /// requested with up to `concurrency` requests in flight. Items that show up
+    /// twice, because the list shifted while it was read (e.g. new scrobbles
+    /// arriving), are kept only where they were first seen. Fails on the first error.
+    ///
+    /// Only the start page and the item cap of the stream apply.
+    pub async fn collect_all(mut self, concurrency: usize) -> Result<Vec<P::Item>, Error> {
+        let first_page = self.next_page;
+        let first = (self.fetch)(first_page).await?;
+
+        let meta = first.pagination();
+        let mut last_page = meta.total_pages;
+        if let Some(max) = self.max_items {
+            let per_page = meta.per_page.max(1) as usize;
+            let pages_needed = max.div_ceil(per_page).max(1) as u32;
+            last_page = last_page.min(first_page + pages_needed - 1);
+        }
+
+        let mut seen = HashSet::new();
+        let mut items = Vec::new();
+        let mut push_page = |page: P| {
+            for item in page.into_items() {
+                if seen.insert(P::item_key(&item)) {
+                    items.push(item);
+                }
+            }
+        };
+        push_page(first);
+
+        let fetch = &mut self.fetch;
+        let mut pages = futures::stream::iter(first_page + 1..=last_page)
+            .map(|page| {
+                let request = fetch(page);
+                async move { (page, request.await) }
+            })
+            .buffered(concurrency.max(1));
+
+        while let Some((page, response)) = pages.next().await {
+            let response = response?;
+            // A repeated last page means the list shrank; its items are duplicates.
+            if response.pagination().page == page {
+                push_page(response);
+            }
+        }
+
+        if let Some(max) = self.max_items {
+            items.truncate(max);
+        }
+        Ok(items)
+    }
+
+    fn cap_reached(&self, count: usize) -> bool {
+        self.max_items.is_some_and(|max| count >= max)
+    }
+
+    fn request_next_page(&mut self) {
+        let page = self.next_page;
+        self.next_page += 1;
+        self.pending = Some((page, Box::pin((self.fetch)(page))));
+    }
+
+    /// Buffers the items of `response` and decides whether to prefetch another page.
+    fn accept(&mut self, page: u32, response: P) {
+        let meta = response.pagination();
+        let total_pages = meta.total_pages;
+
+        // Past the end, Last.fm answers with the last page again instead of an empty
+        // one; its items were already yielded, so drop them.
+        if meta.page != page || (page > 1 && page > total_pages) {
+            self.done = true;
+            return;
+        }
+
+        let items = response.into_items();
+        let empty_page = items.is_empty();
+
+        let seen = &mut self.seen;
+        self.buffer.extend(
+            items
+                .into_iter()
+                .filter(|item| seen.insert(P::item_key(item))),
+        );
+
+        if empty_page || page >= total_pages || self.cap_reached(self.yielded + self.buffer.len()) {
+            self.done = true;
+        } else {
+            self.request_next_page();
+        }
+    }
+}
+
+impl<F, Fut, P> Stream for PageStream<F, Fut, P>
+where
+    F: FnMut(u32) -> Fut,
+    Fut: Future<Output = Result<P, Error>>,
+    P: Paginated,
+{
+    type Item = Result<P::Item, Error>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+
+        loop {
+            if this.cap_reached(this.yielded) {
+                this.pending = None;
+                return Poll::Ready(None);
+            }
+
+            // Keep the next page loading while buffered items are handed out.
+            if let Some((page, request)) = &mut this.pending
+                && let Poll::Ready(result) = request.as_mut().poll(cx)
+            {
+                this.fetched = Some((*page, result));
+                this.pending = None;
+            }
+
+            if let Some(item) = this.buffer.pop_front() {
+                this.yielded += 1;
+                return Poll::Ready(Some(Ok(item)));
+            }
+
+            match this.fetched.take() {
+                Some((page, Ok(response))) => {
+                    this.accept(page, response);
+                    continue;
+                }
+                Some((_, Err(err))) => {
+                    this.done = true;
+                    return Poll::Ready(Some(Err(err)));
+                }
+                None => {}
+            }
+
+            if this.pending.is_some() {
+                return Poll::Pending;
+            }
+            if this.done {
+                return Poll::Ready(None);
+            }
+            this.request_next_page();
+        }
+    }
+}
@@ -0,0 +1,173 @@
+//! Client-side rate limiting.
+//!
+//! Last.fm asks for no more than about five requests per second per API key.
+
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use tracing::debug;
+
+/// Configuration of a [`RateLimiter`].
+#[derive(Debug, Clone)]
+pub struct RateLimit {
+    /// Sustained number of requests per second.
+    pub requests_per_second: f64,
+    /// Number of requests that may be sent at once after a quiet period.
+    pub burst: u32,
+    /// Lowest rate the limiter slows down to after rate-limit errors, as a
+    /// fraction of `requests_per_second`.
+    pub min_rate_fraction: f64,
+}
+
+impl Default for RateLimit {
+    fn default() -> Self {
+        Self {
+            requests_per_second: 5.0,
+            burst: 5,
+            min_rate_fraction: 0.1,
+        }
+    }
+}
+
+/// A snapshot of a [`RateLimiter`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct RateLimiterState {
+    /// Requests that can be sent right now without waiting. Negative while
+    /// requests are queued.
+    pub available: f64,
+    /// The rate currently enforced, lowered after rate-limit errors.
+    pub current_rate: f64,
+    /// The configured rate the limiter recovers to.
+    pub max_rate: f64,
+    /// Number of rate-limit errors reported so far.
+    pub rate_limited: u64,
+}
+
+/// A token bucket shared by a [`Client`](crate::Client) and all of its clones.
+///
+/// After a rate-limit error (Last.fm error 29 or HTTP 429) the rate is halved,
+/// down to `min_rate_fraction` of the configured rate. Every successful response
+/// then raises it by a twentieth of the configured rate until it is restored.
+#[derive(Debug)]
+pub struct RateLimiter {
+    limit: RateLimit,
+    bucket: Mutex<Bucket>,
+}
+
+#[derive(Debug)]
+struct Bucket {
+    tokens: f64,
+    rate: f64,
+    refilled_at: Instant,
+    rate_limited: u64,
+    /// Number of requests that took a token so far.
+    reserved: u64,
+}
+
+impl Bucket {
+    fn refill(&mut self, burst: f64) {
+        let now = Instant::now();
+        let elapsed = now.duration_since(self.refilled_at).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * self.rate).min(burst);
+        self.refilled_at = now;
+    }
+}
+
+impl RateLimiter {
+    /// A limiter with a full bucket.
+    pub fn new(limit: RateLimit) -> Self {
+        let limit = RateLimit {
+            requests_per_second: limit.requests_per_second.max(f64::MIN_POSITIVE),
+            burst: limit.burst.max(1),
+            min_rate_fraction: limit.min_rate_fraction.clamp(f64::MIN_POSITIVE, 1.0),
+        };
+
+        Self {
+            bucket: Mutex::new(Bucket {
+                tokens: f64::from(limit.burst),
+                rate: limit.requests_per_second,
+                refilled_at: Instant::now(),
+                rate_limited: 0,
+                reserved: 0,
+            }),
+            limit,
+        }
+    }
+
+    /// The configuration of this limiter.
+    pub fn limit(&self) -> &RateLimit {
+        &self.limit
+    }
+
+    /// The current state of the bucket.
+    pub fn state(&self) -> RateLimiterState {
+        let mut bucket = self.bucket.lock().unwrap();
+        bucket.refill(f64::from(self.limit.burst));
+
+        RateLimiterState {
+            available: bucket.tokens,
+            current_rate: bucket.rate,
+            max_rate: self.limit.requests_per_second,
+            rate_limited: bucket.rate_limited,
+        }
+    }
+
+    /// Wait until a request may be sent.
+    ///
+    /// Each caller reserves its slot right away, so waiting requests are let
+    /// through in the order they arrived. The remaining wait is recomputed at the
+    /// current rate after every sleep, so slowing down also holds back requests
+    /// that are already queued.
+    pub async fn acquire(&self) {
+        let ticket = {
+            let mut bucket = self.bucket.lock().unwrap();
+            bucket.refill(f64::from(self.limit.burst));
+            bucket.tokens -= 1.0;
+            bucket.reserved += 1;
+
+            if bucket.tokens >= 0.0 {
+                return;
+            }
+            bucket.reserved
+        };
+
+        loop {
+            let wait = {
+                let mut bucket = self.bucket.lock().unwrap();
+                bucket.refill(f64::from(self.limit.burst));
+
+                // Tokens taken by requests queued after this one don't delay it.
+                let queued_behind = (bucket.reserved - ticket) as f64;
+                let missing = -(bucket.tokens + queued_behind);
+                if missing <= 0.0 {
+                    return;
+                }
+                Duration::from_secs_f64(missing / bucket.rate)
+            };
+
+            debug!(?wait, "Waiting for rate limiter");
+            tokio::time::sleep(wait).await;
+        }
+    }
+
+    /// Slow down after Last.fm reported that the rate limit was exceeded.
+    pub(crate) fn on_rate_limited(&self) {
+        let mut bucket = self.bucket.lock().unwrap();
+        bucket.refill(f64::from(self.limit.burst));
+
+        let min_rate = self.limit.requests_per_second * self.limit.min_rate_fraction;
+        bucket.rate = (bucket.rate / 2.0).max(min_rate);
+        bucket.tokens = bucket.tokens.min(0.0);
+        bucket.rate_limited += 1;
+    }
+
+    /// Speed back up towards the configured rate after a successful request.
+    pub(crate) fn on_success(&self) {
+        let mut bucket = self.bucket.lock().unwrap();
+        if bucket.rate < self.limit.requests_per_second {
+            bucket.refill(f64::from(self.limit.burst));
+            bucket.rate = (bucket.rate + self.limit.requests_per_second / 20.0)
+                .min(self.limit.requests_per_second);
+        }
+    }
+}
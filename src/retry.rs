@@ -0,0 +1,124 @@
+//! Retrying requests that failed for transient reasons.
+
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::time::{Duration, SystemTime};
+
+use chrono::{DateTime, Utc};
+
+use crate::error::{Error, LastFmErrorCode};
+
+/// When and how often failed requests are retried.
+///
+/// Only transient failures are retried: Last.fm error codes 8, 11, 16 and 29,
+/// HTTP 5xx responses (including HTML maintenance pages and empty bodies), and
+/// connection errors or timeouts.
+///
+/// Signed POST requests are only retried when the request certainly wasn't
+/// processed: the connection couldn't be made, or Last.fm reported that it is
+/// offline or rate limiting. A timeout or 5xx on a write (scrobble, love, tag) may
+/// have happened after it was stored, so retrying it could duplicate it, and a
+/// token passed to `auth.getSession` may already have been used up.
+///
+/// The delay before retry `n` is `initial_backoff * multiplier^(n - 1)`, capped
+/// at `max_backoff`. With `jitter`, a random part of up to half of it is dropped
+/// so that many clients don't retry in lockstep.
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    /// Total attempts per request, including the first one. `1` disables retries.
+    pub max_attempts: u32,
+    pub initial_backoff: Duration,
+    pub max_backoff: Duration,
+    pub multiplier: f64,
+    pub jitter: bool,
+    /// Wait at least as long as a `Retry-After` response header asks for.
+    ///
+    /// If the header asks for longer than `max_backoff`, the request is not retried
+    /// and the error is returned instead.
+    pub respect_retry_after: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(30),
+            multiplier: 2.0,
+            jitter: true,
+            respect_retry_after: true,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// A policy that never retries. This is what clients use unless configured otherwise.
+    pub fn none() -> Self {
+        Self {
+            max_attempts: 1,
+            ..Self::default()
+        }
+    }
+
+    /// Delay before retry number `retry` (starting at 1), or `None` if `Retry-After`
+    /// asks for longer than `max_backoff`.
+    pub(crate) fn backoff(&self, retry: u32, retry_after: Option<Duration>) -> Option<Duration> {
+        let exponent = retry.saturating_sub(1).min(i32::MAX as u32) as i32;
+        let mut delay = self
+            .initial_backoff
+            .mul_f64(self.multiplier.max(1.0).powi(exponent).min(1e9))
+            .min(self.max_backoff);
+
+        if self.jitter {
+            delay = delay.mul_f64(1.0 - random_fraction() / 2.0);
+        }
+
+        match retry_after {
+            Some(wait) if self.respect_retry_after && wait > self.max_backoff => None,
+            Some(wait) if self.respect_retry_after => Some(delay.max(wait)),
+            _ => Some(delay),
+        }
+    }
+
+    /// Whether `error` may be retried. `write` marks non-idempotent requests.
+    pub(crate) fn should_retry(&self, error: &Error, write: bool) -> bool {
+        match error {
+            // These are returned before a request is processed at all.
+            Error::LastFm(err) if write => matches!(
+                err.code(),
+                LastFmErrorCode::ServiceOffline | LastFmErrorCode::RateLimitExceeded
+            ),
+            Error::LastFm(err) => err.code().is_retryable(),
+            Error::Http { status, .. } => !write && status.is_server_error(),
+            // Outage pages and empty bodies come from Last.fm's frontends, not the API.
+            Error::HtmlPage { status, .. } | Error::EmptyResponse { status } => {
+                !write && status.is_server_error()
+            }
+            Error::Request(err) if write => err.is_connect(),
+            Error::Request(err) => err.is_connect() || err.is_timeout(),
+            _ => false,
+        }
+    }
+}
+
+/// Parses a `Retry-After` header, given either in seconds or as an HTTP date.
+pub(crate) fn parse_retry_after(value: &str) -> Option<Duration> {
+    let value = value.trim();
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+
+    let at = DateTime::parse_from_rfc2822(value)
+        .ok()?
+        .with_timezone(&Utc);
+    Some((at - Utc::now()).to_std().unwrap_or(Duration::ZERO))
+}
+
+/// A number in `[0, 1)` that is good enough for spreading out retries.
+fn random_fraction() -> f64 {
+    let mut hasher = RandomState::new().build_hasher();
+    if let Ok(now) = SystemTime::now().duration_since(SystemTime::UNIX_EPOCH) {
+        hasher.write_u128(now.as_nanos());
+    }
+    (hasher.finish() >> 11) as f64 / (1u64 << 53) as f64
+}
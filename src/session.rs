@@ -0,0 +1,140 @@
+//! Persistence for authenticated Last.fm sessions.
+//!
+//! Session keys don't expire, so an application only needs to run the auth flow
+//! once and can reuse the stored session afterwards. See
+//! [`ClientBuilder::build_with_session`](crate::client::ClientBuilder::build_with_session).
+
+use std::future::Future;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use tokio::fs;
+use tokio::io::AsyncWriteExt;
+
+use crate::auth::Session;
+use crate::error::Error;
+
+/// Somewhere to keep a [`Session`] between runs.
+///
+/// The methods are async so that stores backed by files or other I/O don't block
+/// the runtime.
+pub trait SessionStore {
+    /// Load the stored session, or `None` if there is none.
+    fn load(&self) -> impl Future<Output = Result<Option<Session>, Error>> + Send;
+
+    /// Store `session`, replacing any previous one.
+    fn save(&self, session: &Session) -> impl Future<Output = Result<(), Error>> + Send;
+
+    /// Remove the stored session, if any.
+    fn clear(&self) -> impl Future<Output = Result<(), Error>> + Send;
+}
+
+/// Stores the session as JSON in a file readable only by its owner.
+///
+/// On Unix the file is created with `0600` permissions. Writes go to a temporary
+/// file next to it first, so a crash never leaves a half-written session behind.
+#[derive(Debug, Clone)]
+pub struct FileSessionStore {
+    path: PathBuf,
+}
+
+impl FileSessionStore {
+    /// A store backed by the file at `path`. Missing parent directories are
+    /// created on the first save.
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    /// The file the session is stored in.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl SessionStore for FileSessionStore {
+    async fn load(&self) -> Result<Option<Session>, Error> {
+        match fs::read(&self.path).await {
+            Ok(data) => Ok(Some(serde_json::from_slice(&data)?)),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    async fn save(&self, session: &Session) -> Result<(), Error> {
+        if let Some(parent) = self.path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            fs::create_dir_all(parent).await?;
+        }
+
+        let mut tmp_name = self.path.file_name().unwrap_or_default().to_os_string();
+        tmp_name.push(".tmp");
+        let tmp = self.path.with_file_name(tmp_name);
+
+        let mut file = owner_only_file(&tmp).await?;
+        file.write_all(&serde_json::to_vec_pretty(session)?).await?;
+        file.sync_all().await?;
+        drop(file);
+
+        fs::rename(&tmp, &self.path).await?;
+        Ok(())
+    }
+
+    async fn clear(&self) -> Result<(), Error> {
+        match fs::remove_file(&self.path).await {
+            Err(err) if err.kind() != io::ErrorKind::NotFound => Err(err.into()),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Create (or truncate) `path` so that only its owner can read it.
+#[cfg(unix)]
+async fn owner_only_file(path: &Path) -> io::Result<fs::File> {
+    use std::os::unix::fs::PermissionsExt;
+
+    let file = fs::OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(true)
+        .mode(0o600)
+        .open(path)
+        .await?;
+    // `mode` only applies to newly created files.
+    file.set_permissions(std::fs::Permissions::from_mode(0o600))
+        .await?;
+    Ok(file)
+}
+
+#[cfg(not(unix))]
+async fn owner_only_file(path: &Path) -> io::Result<fs::File> {
+    fs::File::create(path).await
+}
+
+/// Keeps the session in memory, e.g. for tests or short-lived processes.
+#[derive(Debug, Default)]
+pub struct MemorySessionStore {
+    session: Mutex<Option<Session>>,
+}
+
+impl MemorySessionStore {
+    /// An empty store.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl SessionStore for MemorySessionStore {
+    async fn load(&self) -> Result<Option<Session>, Error> {
+        Ok(self.session.lock().unwrap().clone())
+    }
+
+    async fn save(&self, session: &Session) -> Result<(), Error> {
+        *self.session.lock().unwrap() = Some(session.clone());
+        Ok(())
+    }
+
+    async fn clear(&self) -> Result<(), Error> {
+        *self.session.lock().unwrap() = None;
+        Ok(())
+    }
+}
@@ -1,26 +1,81 @@
 //! Utility functions for common operations like timestamp parsing, and formatting.
 
+use std::fmt;
+use std::marker::PhantomData;
 use std::str::FromStr;
 use std::time::{SystemTime, UNIX_EPOCH};
 
 use chrono::{DateTime, TimeZone, Utc};
-use serde::de::{self, Deserialize, Deserializer};
+use serde::de::{self, Deserialize, DeserializeOwned, Deserializer, Visitor};
+
+/// Parses a scalar that Last.fm may send either as a string or as a bare number.
+struct FromStrVisitor<T>(PhantomData<T>);
+
+impl<T> Visitor<'_> for FromStrVisitor<T>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    type Value = T;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a string or a number")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<T, E> {
+        v.parse().map_err(E::custom)
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<T, E> {
+        self.visit_str(&v.to_string())
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<T, E> {
+        self.visit_str(&v.to_string())
+    }
+
+    fn visit_f64<E: de::Error>(self, v: f64) -> Result<T, E> {
+        self.visit_str(&v.to_string())
+    }
+}
+
+/// Like [`FromStrVisitor`], but `null` becomes `None`.
+struct FromStrOptVisitor<T>(PhantomData<T>);
+
+impl<'de, T> Visitor<'de> for FromStrOptVisitor<T>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    type Value = Option<T>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a string, a number or null")
+    }
+
+    fn visit_none<E: de::Error>(self) -> Result<Option<T>, E> {
+        Ok(None)
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<Option<T>, E> {
+        Ok(None)
+    }
+
+    fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<Option<T>, D::Error> {
+        from_str(deserializer).map(Some)
+    }
+}
 
 /// Generic helper to parse numeric types from strings
+///
+/// Bare JSON numbers are accepted too, since Last.fm isn't consistent about quoting.
 pub fn from_str_opt<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
 where
     D: Deserializer<'de>,
     T: FromStr,
-    T::Err: std::fmt::Display,
+    T::Err: fmt::Display,
 {
-    let opt = Option::<String>::deserialize(deserializer)?;
-    match opt {
-        Some(s) => {
-            let parsed = s.parse().map_err(de::Error::custom)?;
-            Ok(Some(parsed))
-        }
-        None => Ok(None),
-    }
+    deserializer.deserialize_option(FromStrOptVisitor(PhantomData))
 }
 
 /// Same as above, but for required fields
@@ -28,10 +83,73 @@ pub fn from_str<'de, D, T>(deserializer: D) -> Result<T, D::Error>
 where
     D: Deserializer<'de>,
     T: FromStr,
-    T::Err: std::fmt::Display,
+    T::Err: fmt::Display,
+{
+    deserializer.deserialize_any(FromStrVisitor(PhantomData))
+}
+
+/// Parses a UNIX timestamp string into a `DateTime<Utc>`.
+pub fn datetime_from_str<'de, D>(deserializer: D) -> Result<DateTime<Utc>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let timestamp: i64 = from_str(deserializer)?;
+    Ok(timestamp_to_datetime(timestamp))
+}
+
+/// Parses Last.fm's `"0"`/`"1"` string flags into a `bool`.
+pub fn from_str_bool<'de, D>(deserializer: D) -> Result<bool, D::Error>
+where
+    D: Deserializer<'de>,
 {
-    let s = String::deserialize(deserializer)?;
-    s.parse().map_err(de::Error::custom)
+    let n: u8 = from_str(deserializer)?;
+    Ok(n != 0)
+}
+
+/// Same as above, but for optional flags
+pub fn from_str_bool_opt<'de, D>(deserializer: D) -> Result<Option<bool>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let n: Option<u8> = from_str_opt(deserializer)?;
+    Ok(n.map(|n| n != 0))
+}
+
+/// Deserializes either a single object or an array of objects into a `Vec`.
+///
+/// Last.fm collapses one-element lists into a bare object, so list fields
+/// that may contain a single entry need this.
+pub fn one_or_many<'de, D, T>(deserializer: D) -> Result<Vec<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    #[derive(serde::Deserialize)]
+    #[serde(untagged)]
+    enum OneOrMany<T> {
+        Many(Vec<T>),
+        One(T),
+    }
+
+    match OneOrMany::deserialize(deserializer)? {
+        OneOrMany::Many(items) => Ok(items),
+        OneOrMany::One(item) => Ok(vec![item]),
+    }
+}
+
+/// Deserializes `T`, falling back to `T::default()` when Last.fm sends a placeholder instead.
+///
+/// Some empty objects (tags, corrections) come back as a whitespace string like `"\n "`.
+/// Only empty or whitespace-only strings count as blank; anything else must parse as `T`.
+pub fn blank_as_default<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: DeserializeOwned + Default,
+{
+    match serde_json::Value::deserialize(deserializer)? {
+        serde_json::Value::String(s) if s.trim().is_empty() => Ok(T::default()),
+        value => T::deserialize(value).map_err(de::Error::custom),
+    }
 }
 
 /// Masks an API key by replacing all but the first 3 characters with `*`.
@@ -45,6 +163,46 @@ pub fn mask_api_key(api_key: &str) -> String {
         .collect()
 }
 
+/// Replaces every occurrence of each secret in `text` with its masked form.
+///
+/// The values of `"key"` and `"sk"` fields in `text` are treated as secrets too,
+/// since session keys show up in `auth.getSession` responses.
+pub fn mask_secrets(text: &str, secrets: &[&str]) -> String {
+    let mut masked = text.to_string();
+    let fields = secret_fields(text);
+    for secret in secrets.iter().chain(&fields).filter(|s| !s.is_empty()) {
+        masked = masked.replace(secret, &mask_api_key(secret));
+    }
+    masked
+}
+
+/// The string values of `"key"` and `"sk"` fields in a (possibly malformed) JSON text.
+pub fn secret_fields(text: &str) -> Vec<&str> {
+    let mut values = Vec::new();
+
+    for name in ["\"key\"", "\"sk\""] {
+        for (at, _) in text.match_indices(name) {
+            let rest = text[at + name.len()..].trim_start();
+            let Some(rest) = rest.strip_prefix(':') else {
+                continue;
+            };
+            let Some(rest) = rest.trim_start().strip_prefix('"') else {
+                continue;
+            };
+
+            let mut escaped = false;
+            let end = rest.find(|c| {
+                let quote = c == '"' && !escaped;
+                escaped = c == '\\' && !escaped;
+                quote
+            });
+            values.push(&rest[..end.unwrap_or(rest.len())]);
+        }
+    }
+
+    values
+}
+
 /// Returns the current UNIX timestamp in seconds (UTC).
 pub fn timestamp_now() -> i64 {
     SystemTime::now()
@@ -53,6 +211,38 @@ pub fn timestamp_now() -> i64 {
         .unwrap_or_else(|_| 0)
 }
 
+/// Bytes of context kept on each side of the error position by [`body_excerpt`].
+const EXCERPT_RADIUS: usize = 120;
+
+/// A short excerpt of `body` around `line` and `column` (1-based, as reported by
+/// `serde_json`), with every secret in `secrets` masked.
+pub fn body_excerpt(body: &str, line: usize, column: usize, secrets: &[&str]) -> String {
+    let redacted = mask_secrets(body, secrets);
+
+    let line_start: usize = redacted
+        .split_inclusive('\n')
+        .take(line.saturating_sub(1))
+        .map(str::len)
+        .sum();
+    let position = (line_start + column.saturating_sub(1)).min(redacted.len());
+
+    let mut start = position.saturating_sub(EXCERPT_RADIUS);
+    while !redacted.is_char_boundary(start) {
+        start -= 1;
+    }
+    let mut end = (position + EXCERPT_RADIUS).min(redacted.len());
+    while !redacted.is_char_boundary(end) {
+        end += 1;
+    }
+
+    format!(
+        "{}{}{}",
+        if start > 0 { "…" } else { "" },
+        &redacted[start..end],
+        if end < redacted.len() { "…" } else { "" }
+    )
+}
+
 /// Converts a UNIX timestamp to a `DateTime<Utc>`.
 pub fn timestamp_to_datetime(timestamp: i64) -> DateTime<Utc> {
     // `timestamp_opt` returns a `LocalResult` which can be `None` if the timestamp
@@ -63,6 +253,11 @@ pub fn timestamp_to_datetime(timestamp: i64) -> DateTime<Utc> {
         .unwrap_or_else(|| Utc.timestamp_opt(0, 0).single().unwrap())
 }
 
+/// Converts a `DateTime<Utc>` to a UNIX timestamp in seconds.
+pub fn datetime_to_timestamp(dt: DateTime<Utc>) -> i64 {
+    dt.timestamp()
+}
+
 /// Formats a `DateTime<Utc>` as an ISO 8601 string.
 pub fn format_datetime_iso(dt: DateTime<Utc>) -> String {
     dt.to_rfc3339()
@@ -92,14 +287,12 @@ pub fn format_duration_human(seconds: u64) -> String {
 pub fn format_number(num: i64) -> String {
     let num_str = num.to_string();
     let mut result = String::new();
-    let mut count = 0;
 
-    for c in num_str.chars().rev() {
+    for (count, c) in num_str.chars().rev().enumerate() {
         if count > 0 && count % 3 == 0 {
             result.push(',');
         }
         result.push(c);
-        count += 1;
     }
 
     // Reverse the result to get the correct order
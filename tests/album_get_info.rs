@@ -0,0 +1,50 @@
+mod common;
+
+use common::{MockResponse, MockServer};
+
+#[tokio::test]
+async fn test_album_get_info() {
+    let body = r##"{"album":{"artist":"Cher","mbid":"63b3a8ca-26f2-4e2b-b867-647a6ec2bebd","tags":{"tag":[{"url":"https://www.last.fm/tag/pop","name":"pop"},{"url":"https://www.last.fm/tag/dance","name":"dance"}]},"playcount":"2925087","image":[{"size":"small","#text":"https://lastfm.freetls.fastly.net/i/u/34s/1.png"}],"tracks":{"track":[
+        {"streamable":{"fulltrack":"0","#text":"0"},"duration":239,"url":"https://www.last.fm/music/Cher/_/Believe","name":"Believe","@attr":{"rank":1},"artist":{"url":"https://www.last.fm/music/Cher","name":"Cher","mbid":"bfcc6d75-a6a5-4bc6-8282-47aec8531818"}},
+        {"streamable":{"fulltrack":"0","#text":"0"},"duration":null,"url":"https://www.last.fm/music/Cher/_/The+Power","name":"The Power","@attr":{"rank":2},"artist":{"url":"https://www.last.fm/music/Cher","name":"Cher","mbid":"bfcc6d75-a6a5-4bc6-8282-47aec8531818"}}
+    ]},"url":"https://www.last.fm/music/Cher/Believe","name":"Believe","listeners":"446013","userplaycount":"42","wiki":{"published":"27 Jul 2008, 15:55","summary":"Believe is the twenty-third studio album.","content":"Believe is the twenty-third studio album by Cher."}}}"##;
+    let server = MockServer::start(vec![MockResponse::json(body)]).await;
+
+    let album = server
+        .client()
+        .album()
+        .get_info(("Cher", "Believe"), false, None, Some("tester"))
+        .await
+        .expect("Failed to fetch album info");
+
+    assert_eq!(album.name, "Believe");
+    assert_eq!(album.userplaycount, Some(42));
+    assert_eq!(album.tags.tag.len(), 2);
+    assert_eq!(album.tracks.track[0].duration, Some(239));
+    assert_eq!(album.tracks.track[1].duration, None);
+    assert_eq!(album.tracks.track[1].attr.rank, 2);
+    assert!(album.wiki.is_some());
+
+    let params = server.requests()[0].params();
+    assert_eq!(params["method"], "album.getInfo");
+    assert_eq!(params["artist"], "Cher");
+    assert_eq!(params["album"], "Believe");
+    assert_eq!(params["username"], "tester");
+}
+
+#[tokio::test]
+async fn test_album_get_info_without_tags() {
+    let body = r#"{"album":{"artist":"Nobody","tags":"","playcount":"1","image":[],"url":"https://www.last.fm/music/Nobody/Demo","name":"Demo","listeners":"1"}}"#;
+    let server = MockServer::start(vec![MockResponse::json(body)]).await;
+
+    let album = server
+        .client()
+        .album()
+        .get_info(("Nobody", "Demo"), false, None, None)
+        .await
+        .expect("Failed to fetch album info");
+
+    assert!(album.tags.tag.is_empty());
+    assert!(album.tracks.track.is_empty());
+    assert_eq!(album.userplaycount, None);
+}
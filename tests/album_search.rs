@@ -0,0 +1,23 @@
+use std::env;
+
+use soniq::client::Client;
+
+#[tokio::test]
+async fn test_album_search() {
+    dotenv::dotenv().expect("Failed to load .env file");
+
+    let api_key = env::var("LASTFM_API_KEY").expect("Set LASTFM_API_KEY env var");
+
+    let client = Client::builder(api_key)
+        .build()
+        .expect("Failed to build client");
+
+    let results = client
+        .album()
+        .search("believe", Some(5), None)
+        .await
+        .expect("Failed to search albums");
+
+    assert!(results.meta.total_results > 0, "Expected search results");
+    assert!(!results.albummatches.album.is_empty());
+}
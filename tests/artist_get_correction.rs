@@ -0,0 +1,48 @@
+mod common;
+
+use common::{MockResponse, MockServer};
+
+#[tokio::test]
+async fn test_artist_get_correction() {
+    let body = r#"{"corrections":{"correction":{"artist":{"name":"Guns N' Roses","mbid":"eeb1195b-f213-4ce1-b28c-8565211f8e43","url":"https://www.last.fm/music/Guns+N%27+Roses"},"@attr":{"index":"0"}}}}"#;
+    let server = MockServer::start(vec![MockResponse::json(body)]).await;
+
+    let correction = server
+        .client()
+        .artist()
+        .get_correction("guns and roses")
+        .await
+        .expect("Failed to fetch correction")
+        .expect("Expected a correction");
+
+    assert_eq!(correction.artist.name, "Guns N' Roses");
+    assert_eq!(server.requests()[0].params()["artist"], "guns and roses");
+}
+
+#[tokio::test]
+async fn test_artist_get_correction_none() {
+    let server = MockServer::start(vec![MockResponse::json(r#"{"corrections":"\n "}"#)]).await;
+
+    let correction = server
+        .client()
+        .artist()
+        .get_correction("Cher")
+        .await
+        .expect("Failed to fetch correction");
+
+    assert!(correction.is_none());
+}
+
+#[tokio::test]
+async fn test_artist_get_correction_malformed() {
+    let body = r#"{"corrections":{"correction":{"artist":{"mbid":"eeb1195b-f213-4ce1-b28c-8565211f8e43"}}}}"#;
+    let server = MockServer::start(vec![MockResponse::json(body)]).await;
+
+    let result = server
+        .client()
+        .artist()
+        .get_correction("guns and roses")
+        .await;
+
+    assert!(matches!(result, Err(soniq::Error::Deserialize { .. })));
+}
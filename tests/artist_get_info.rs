@@ -0,0 +1,33 @@
+use std::env;
+
+use soniq::client::Client;
+use soniq::endpoints::artist::ArtistLookup;
+
+#[tokio::test]
+async fn test_artist_get_info() {
+    dotenv::dotenv().expect("Failed to load .env file");
+
+    let api_key = env::var("LASTFM_API_KEY").expect("Set LASTFM_API_KEY env var");
+
+    let client = Client::builder(api_key)
+        .build()
+        .expect("Failed to build client");
+
+    let by_name = client
+        .artist()
+        .get_info("cher", true, None, None)
+        .await
+        .expect("Failed to fetch artist info by name");
+
+    assert_eq!(by_name.name, "Cher");
+    assert!(by_name.stats.listeners > 0, "Expected listeners");
+
+    let mbid = by_name.mbid.expect("Cher should have an MBID");
+    let by_mbid = client
+        .artist()
+        .get_info(ArtistLookup::mbid(mbid), false, Some("en"), None)
+        .await
+        .expect("Failed to fetch artist info by MBID");
+
+    assert_eq!(by_mbid.name, "Cher");
+}
@@ -0,0 +1,28 @@
+mod common;
+
+use common::{MockResponse, MockServer};
+
+#[tokio::test]
+async fn test_artist_get_top_tracks() {
+    let body = r##"{"toptracks":{"track":[
+        {"name":"Believe","playcount":"4452389","listeners":"781393","mbid":"","url":"https://www.last.fm/music/Cher/_/Believe","streamable":"0","artist":{"name":"Cher","mbid":"bfcc6d75-a6a5-4bc6-8282-47aec8531818","url":"https://www.last.fm/music/Cher"},"image":[{"#text":"","size":"small"}],"@attr":{"rank":"1"}}
+    ],"@attr":{"artist":"Cher","page":"2","perPage":"1","totalPages":"1500","total":"1500"}}}"##;
+    let server = MockServer::start(vec![MockResponse::json(body)]).await;
+
+    let top = server
+        .client()
+        .artist()
+        .get_top_tracks("cher", true, Some(1), Some(2))
+        .await
+        .expect("Failed to fetch top tracks");
+
+    assert_eq!(top.attr.artist.as_deref(), Some("Cher"));
+    assert_eq!(top.attr.total_pages, 1500);
+    assert_eq!(top.track[0].attr.rank, 1);
+    assert_eq!(top.track[0].playcount, 4452389);
+
+    let params = server.requests()[0].params();
+    assert_eq!(params["autocorrect"], "1");
+    assert_eq!(params["limit"], "1");
+    assert_eq!(params["page"], "2");
+}
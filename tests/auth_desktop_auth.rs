@@ -0,0 +1,86 @@
+mod common;
+
+use std::time::Duration;
+
+use common::{MockResponse, MockServer};
+use soniq::auth::{AuthPoll, PollBackoff};
+
+const TOKEN: &str = r#"{"token":"cf45fe5a3e3cebe168480a086d7fe481"}"#;
+const NOT_AUTHORIZED: &str =
+    r#"{"error":14,"message":"Unauthorized Token - This token has not been authorized"}"#;
+const EXPIRED: &str = r#"{"error":15,"message":"This token has expired"}"#;
+const SESSION: &str =
+    r#"{"session":{"name":"tester","key":"d580d57f32848f5dcf574d1ce18d78b2","subscriber":0}}"#;
+
+#[tokio::test]
+async fn test_desktop_auth_url() {
+    let server = MockServer::start(vec![MockResponse::json(TOKEN)]).await;
+    let client = server.client();
+
+    let auth = client.desktop_auth().await.expect("Failed to get token");
+
+    assert_eq!(auth.token(), "cf45fe5a3e3cebe168480a086d7fe481");
+    assert_eq!(
+        auth.url().as_str(),
+        "https://www.last.fm/api/auth/?api_key=test_api_key&token=cf45fe5a3e3cebe168480a086d7fe481"
+    );
+}
+
+#[tokio::test]
+async fn test_desktop_auth_waits_for_approval() {
+    let server = MockServer::start(vec![
+        MockResponse::json(TOKEN),
+        MockResponse::status(403, NOT_AUTHORIZED),
+        MockResponse::status(403, NOT_AUTHORIZED),
+        MockResponse::json(SESSION),
+    ])
+    .await;
+    let client = server.client();
+
+    let auth = client.desktop_auth().await.expect("Failed to get token");
+    let session = auth
+        .wait(&PollBackoff::fixed(Duration::from_millis(1)))
+        .await
+        .expect("Failed to get session");
+
+    assert_eq!(session.name, "tester");
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 4);
+    assert_eq!(requests[3].params()["method"], "auth.getSession");
+    assert_eq!(requests[3].params()["token"], auth.token());
+}
+
+#[tokio::test]
+async fn test_desktop_auth_poll_states() {
+    let server = MockServer::start(vec![
+        MockResponse::json(TOKEN),
+        MockResponse::status(403, NOT_AUTHORIZED),
+        MockResponse::status(403, EXPIRED),
+    ])
+    .await;
+    let client = server.client();
+
+    let auth = client.desktop_auth().await.expect("Failed to get token");
+
+    assert!(matches!(auth.poll().await, Ok(AuthPoll::Pending)));
+    assert!(matches!(auth.poll().await, Err(soniq::Error::TokenExpired)));
+}
+
+#[tokio::test]
+async fn test_desktop_auth_stops_on_real_failure() {
+    let server = MockServer::start(vec![
+        MockResponse::json(TOKEN),
+        MockResponse::status(403, r#"{"error":10,"message":"Invalid API key"}"#),
+    ])
+    .await;
+    let client = server.client();
+
+    let auth = client.desktop_auth().await.expect("Failed to get token");
+    let err = auth
+        .wait(&PollBackoff::fixed(Duration::from_millis(1)))
+        .await
+        .expect_err("Invalid API key should fail");
+
+    assert!(matches!(err, soniq::Error::LastFm(ref e) if e.error == 10));
+}
@@ -0,0 +1,52 @@
+mod common;
+
+use std::collections::BTreeMap;
+
+use common::{MockResponse, MockServer};
+use soniq::sig::create_sig;
+
+#[tokio::test]
+async fn test_auth_get_mobile_session() {
+    let body =
+        r#"{"session":{"name":"tester","key":"d580d57f32848f5dcf574d1ce18d78b2","subscriber":0}}"#;
+    let server = MockServer::start(vec![MockResponse::json(body)]).await;
+
+    let session = server
+        .client()
+        .get_mobile_session("tester", "hunter2")
+        .await
+        .expect("Failed to get mobile session");
+
+    assert_eq!(session.name, "tester");
+    assert_eq!(session.key, "d580d57f32848f5dcf574d1ce18d78b2");
+
+    let request = &server.requests()[0];
+    assert_eq!(request.method, "POST");
+    assert!(!request.target.contains("hunter2"));
+
+    let params = request.params();
+    assert_eq!(params["method"], "auth.getMobileSession");
+    assert_eq!(params["username"], "tester");
+    assert_eq!(params["password"], "hunter2");
+
+    let signed: BTreeMap<String, String> = params
+        .iter()
+        .filter(|(k, _)| !matches!(k.as_str(), "api_sig" | "format"))
+        .map(|(k, v)| (k.clone(), v.clone()))
+        .collect();
+    assert_eq!(params["api_sig"], create_sig(&signed, "test_api_secret"));
+}
+
+#[tokio::test]
+async fn test_auth_get_mobile_session_bad_credentials() {
+    let body = r#"{"error":4,"message":"Authentication Failed - You do not have permissions to access the service"}"#;
+    let server = MockServer::start(vec![MockResponse::status(403, body)]).await;
+
+    let err = server
+        .client()
+        .get_mobile_session("tester", "wrong")
+        .await
+        .expect_err("Bad credentials should fail");
+
+    assert!(matches!(err, soniq::Error::LastFm(ref e) if e.error == 4));
+}
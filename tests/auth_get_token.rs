@@ -1,7 +1,6 @@
 use std::env;
 
 use soniq::client::Client;
-use tokio;
 
 #[tokio::test]
 async fn test_auth_get_token() {
@@ -0,0 +1,98 @@
+mod common;
+
+use std::time::Duration;
+
+use common::{MockResponse, MockServer};
+use soniq::auth::CallbackListener;
+
+#[tokio::test]
+async fn test_web_auth_url() {
+    let server = MockServer::start(vec![MockResponse::json("{}")]).await;
+    let client = server.client();
+
+    assert_eq!(
+        client
+            .web_auth_url(Some("http://localhost:8080/cb?x=1"))
+            .as_str(),
+        "https://www.last.fm/api/auth/?api_key=test_api_key&cb=http%3A%2F%2Flocalhost%3A8080%2Fcb%3Fx%3D1"
+    );
+    assert_eq!(
+        client.web_auth_url(None).as_str(),
+        "https://www.last.fm/api/auth/?api_key=test_api_key"
+    );
+}
+
+#[tokio::test]
+async fn test_callback_listener_exchanges_token() {
+    let body =
+        r#"{"session":{"name":"tester","key":"d580d57f32848f5dcf574d1ce18d78b2","subscriber":0}}"#;
+    let server = MockServer::start(vec![MockResponse::json(body)]).await;
+    let client = server.client();
+
+    let listener = CallbackListener::bind("127.0.0.1:0")
+        .await
+        .expect("Failed to bind listener");
+    let callback = listener.callback_url().clone();
+    assert_eq!(callback.path(), "/callback");
+
+    let browser = tokio::spawn(async move {
+        let http = reqwest::Client::new();
+        let favicon = http
+            .get(callback.join("/favicon.ico").unwrap())
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(favicon.status(), 404);
+
+        let redirect = http
+            .get(format!("{callback}?token=cf45fe5a3e3cebe168480a086d7fe481"))
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(redirect.status(), 200);
+    });
+
+    let session = listener
+        .session(&client)
+        .await
+        .expect("Failed to get session");
+    browser.await.unwrap();
+
+    assert_eq!(session.name, "tester");
+
+    let params = server.requests()[0].params();
+    assert_eq!(params["method"], "auth.getSession");
+    assert_eq!(params["token"], "cf45fe5a3e3cebe168480a086d7fe481");
+}
+
+#[tokio::test]
+async fn test_callback_listener_ignores_idle_connection() {
+    let listener = CallbackListener::bind("127.0.0.1:0")
+        .await
+        .expect("Failed to bind listener");
+    let callback = listener.callback_url().clone();
+
+    let browser = tokio::spawn(async move {
+        // A speculative connection that never sends a request.
+        let addr = format!(
+            "{}:{}",
+            callback.host_str().unwrap(),
+            callback.port().unwrap()
+        );
+        let idle = tokio::net::TcpStream::connect(addr).await.unwrap();
+
+        let redirect = reqwest::get(format!("{callback}?token=cf45fe5a3e3cebe168480a086d7fe481"))
+            .await
+            .unwrap();
+        assert_eq!(redirect.status(), 200);
+        drop(idle);
+    });
+
+    let token = tokio::time::timeout(Duration::from_secs(5), listener.token())
+        .await
+        .expect("Listener blocked on the idle connection")
+        .expect("Failed to receive token");
+    browser.await.unwrap();
+
+    assert_eq!(token, "cf45fe5a3e3cebe168480a086d7fe481");
+}
@@ -0,0 +1,68 @@
+mod common;
+
+use common::{MockResponse, MockServer};
+use soniq::auth::Session;
+use soniq::client::Client;
+
+fn session() -> Session {
+    Session {
+        name: "tester".into(),
+        key: "session_key".into(),
+        subscriber: 0,
+    }
+}
+
+#[test]
+fn test_authenticate_requires_api_secret() {
+    let client = Client::builder("test_api_key").build().unwrap();
+
+    let err = client.authenticate(session()).unwrap_err();
+
+    assert!(matches!(err, soniq::Error::MissingApiSecret));
+}
+
+#[tokio::test]
+async fn test_authenticated_client_signs_with_session_key() {
+    let server = MockServer::start(vec![MockResponse::json("{}")]).await;
+    let client = server.authenticated_client();
+
+    assert_eq!(client.username(), "tester");
+
+    client
+        .artist()
+        .add_tags("Cher", &["pop", "dance"])
+        .await
+        .expect("Failed to add tags");
+    client
+        .album()
+        .remove_tag("Cher", "Believe", "pop")
+        .await
+        .expect("Failed to remove tag");
+
+    let requests = server.requests();
+    let params = requests[0].params();
+    assert_eq!(params["method"], "artist.addTags");
+    assert_eq!(params["sk"], "session_key");
+    assert_eq!(params["tags"], "pop,dance");
+    assert!(params.contains_key("api_sig"));
+
+    let params = requests[1].params();
+    assert_eq!(params["method"], "album.removeTag");
+    assert_eq!(params["sk"], "session_key");
+}
+
+#[tokio::test]
+#[allow(deprecated)]
+async fn test_signed_post_with_session_still_signs() {
+    let server = MockServer::start(vec![MockResponse::json("{}")]).await;
+
+    let _: serde::de::IgnoredAny = server
+        .client()
+        .signed_post_with_session("track.love", Some("session_key"), Default::default())
+        .await
+        .expect("Failed to send signed request");
+
+    let params = server.requests()[0].params();
+    assert_eq!(params["sk"], "session_key");
+    assert!(params.contains_key("api_sig"));
+}
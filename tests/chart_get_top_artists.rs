@@ -0,0 +1,24 @@
+use std::env;
+
+use soniq::client::Client;
+
+#[tokio::test]
+async fn test_chart_get_top_artists() {
+    dotenv::dotenv().expect("Failed to load .env file");
+
+    let api_key = env::var("LASTFM_API_KEY").expect("Set LASTFM_API_KEY env var");
+
+    let client = Client::builder(api_key)
+        .build()
+        .expect("Failed to build client");
+
+    let chart = client
+        .chart()
+        .get_top_artists(Some(10), None)
+        .await
+        .expect("Failed to fetch top artists chart");
+
+    assert!(chart.attr.user.is_none());
+    assert!(chart.attr.total > 0, "Expected a non-empty chart");
+    assert!(!chart.artist.is_empty());
+}
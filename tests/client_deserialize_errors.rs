@@ -0,0 +1,93 @@
+mod common;
+
+use common::{MockResponse, MockServer};
+use soniq::endpoints::track::NowPlaying;
+
+#[tokio::test]
+async fn test_deserialize_error_has_method_path_and_excerpt() {
+    let body = r##"{"user":{"name":"tester","age":"0","subscriber":"0","realname":"","bootstrap":"0","playcount":"1","artist_count":"1","playlists":"0","track_count":"1","album_count":"1","image":[],"registered":{"unixtime":"not a number","#text":1100000000},"country":"None","gender":"n","url":"https://www.last.fm/user/tester?api_key=test_api_key","type":"user"}}"##;
+    let server = MockServer::start(vec![MockResponse::json(body)]).await;
+
+    let err = server.client().user().get_info("tester").await.unwrap_err();
+
+    match &err {
+        soniq::Error::Deserialize {
+            method,
+            path,
+            excerpt,
+            ..
+        } => {
+            assert_eq!(method, "user.getInfo");
+            assert_eq!(path, "user.registered.unixtime");
+            assert!(excerpt.contains("not a number"));
+            assert!(excerpt.contains("api_key=tes*********"));
+            assert!(!excerpt.contains("test_api_key"));
+        }
+        other => panic!("expected a deserialize error, got {other:?}"),
+    }
+    assert!(err.to_string().contains("user.registered.unixtime"));
+}
+
+#[tokio::test]
+async fn test_deserialize_error_masks_session_key_and_truncates() {
+    let padding = "x".repeat(1000);
+    let body = format!(
+        r##"{{"nowplaying":{{"padding":"{padding}","artist":{{"corrected":"0","#text":"Low"}},"track":{{"corrected":"0","#text":"session_key"}},"album":{{"corrected":"0","#text":""}},"albumArtist":{{"corrected":"0","#text":""}},"ignoredMessage":{{"code":"nope","#text":""}}}}}}"##
+    );
+    let server = MockServer::start(vec![MockResponse::json(body.clone())]).await;
+
+    let err = server
+        .authenticated_client()
+        .track()
+        .update_now_playing(&NowPlaying::new("Low", "Words"))
+        .await
+        .unwrap_err();
+
+    match err {
+        soniq::Error::Deserialize {
+            method,
+            path,
+            excerpt,
+            ..
+        } => {
+            assert_eq!(method, "track.updateNowPlaying");
+            assert_eq!(path, "nowplaying.ignoredMessage.code");
+            assert!(excerpt.starts_with('…'));
+            assert!(excerpt.len() < body.len());
+            assert!(!excerpt.contains("session_key"));
+        }
+        other => panic!("expected a deserialize error, got {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn test_deserialize_error_masks_session_key_in_body() {
+    let body = r#"{"session":{"name":"tester","key":"SECRET","subscriber":"x"}}"#;
+    let server = MockServer::start(vec![MockResponse::json(body)]).await;
+
+    let err = server
+        .client()
+        .get_session("approved_token")
+        .await
+        .unwrap_err();
+
+    assert!(matches!(err, soniq::Error::Deserialize { .. }));
+    assert!(!err.to_string().contains("SECRET"));
+    assert!(!format!("{err:?}").contains("SECRET"));
+}
+
+#[tokio::test]
+async fn test_deserialize_error_masks_session_key_in_message() {
+    let body = r#"{"session":{"name":"tester","key":"SECRET","subscriber":"SECRET"}}"#;
+    let server = MockServer::start(vec![MockResponse::json(body)]).await;
+
+    let err = server
+        .client()
+        .get_session("approved_token")
+        .await
+        .unwrap_err();
+
+    assert!(err.to_string().contains("SEC***"));
+    assert!(!err.to_string().contains("SECRET"));
+    assert!(!format!("{err:?}").contains("SECRET"));
+}
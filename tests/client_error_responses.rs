@@ -0,0 +1,75 @@
+mod common;
+
+use common::{MockResponse, MockServer};
+use reqwest::StatusCode;
+use soniq::error::LastFmErrorCode;
+
+async fn get_info(response: MockResponse) -> soniq::Error {
+    let server = MockServer::start(vec![response]).await;
+
+    server.client().user().get_info("tester").await.unwrap_err()
+}
+
+#[tokio::test]
+async fn test_error_envelope_with_success_status() {
+    let err = get_info(MockResponse::json(
+        r#"{"error":6,"message":"User not found","links":[]}"#,
+    ))
+    .await;
+
+    assert_eq!(err.code(), Some(LastFmErrorCode::InvalidParameters));
+}
+
+#[tokio::test]
+async fn test_empty_body() {
+    let err = get_info(MockResponse::json("  \n")).await;
+
+    assert!(matches!(err, soniq::Error::EmptyResponse { status } if status == StatusCode::OK));
+}
+
+#[tokio::test]
+async fn test_html_maintenance_page() {
+    let page = "<!DOCTYPE html>\n<html><head><title>\n  Last.fm is down for maintenance\n</title></head><body>Back soon</body></html>";
+    let err = get_info(MockResponse::status(503, page)).await;
+
+    match err {
+        soniq::Error::HtmlPage { status, title } => {
+            assert_eq!(status, StatusCode::SERVICE_UNAVAILABLE);
+            assert_eq!(title.as_deref(), Some("Last.fm is down for maintenance"));
+        }
+        other => panic!("expected an HTML page error, got {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn test_non_json_error_status() {
+    let err = get_info(MockResponse::status(502, "Bad Gateway")).await;
+
+    assert!(
+        matches!(err, soniq::Error::Http { status, ref text } if status == StatusCode::BAD_GATEWAY && text == "Bad Gateway")
+    );
+}
+
+#[tokio::test]
+async fn test_error_bodies_mask_secrets() {
+    let err = get_info(MockResponse::status(
+        502,
+        "upstream rejected ?api_key=test_api_key",
+    ))
+    .await;
+    match err {
+        soniq::Error::Http { text, .. } => {
+            assert_eq!(text, "upstream rejected ?api_key=tes*********");
+        }
+        other => panic!("expected an HTTP error, got {other:?}"),
+    }
+
+    let page = "<html><head><title>Error for test_api_key</title></head></html>";
+    let err = get_info(MockResponse::status(503, page)).await;
+    match err {
+        soniq::Error::HtmlPage { title, .. } => {
+            assert_eq!(title.as_deref(), Some("Error for tes*********"));
+        }
+        other => panic!("expected an HTML page error, got {other:?}"),
+    }
+}
@@ -0,0 +1,108 @@
+mod common;
+
+use std::time::{Duration, Instant};
+
+use common::{MockResponse, MockServer};
+use soniq::client::Client;
+use soniq::rate_limit::RateLimit;
+
+const TOP_TAGS: &str =
+    r#"{"tags":{"tag":[],"@attr":{"page":"1","perPage":"50","totalPages":"0","total":"0"}}}"#;
+
+fn client(server: &MockServer, limit: RateLimit) -> Client {
+    Client::builder("test_api_key")
+        .base_url(&server.url)
+        .unwrap()
+        .rate_limit(limit)
+        .build()
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_rate_limit_is_shared_across_clones() {
+    let server = MockServer::start(vec![MockResponse::json(TOP_TAGS)]).await;
+    let client = client(
+        &server,
+        RateLimit {
+            requests_per_second: 20.0,
+            burst: 2,
+            ..RateLimit::default()
+        },
+    );
+    let clone = client.clone();
+
+    let started = Instant::now();
+    let requests = (0..3).map(|_| async {
+        client.chart().get_top_tags(None, None).await.unwrap();
+        clone.chart().get_top_tags(None, None).await.unwrap();
+    });
+    futures::future::join_all(requests).await;
+
+    // Two requests fit in the burst; the other four wait 50ms each.
+    assert!(started.elapsed() >= Duration::from_millis(190));
+    assert_eq!(server.requests().len(), 6);
+
+    let state = clone.rate_limiter().unwrap().state();
+    assert!(state.available < 1.0);
+    assert_eq!(state.current_rate, 20.0);
+}
+
+#[tokio::test]
+async fn test_rate_limit_slows_down_after_error_29() {
+    let server = MockServer::start(vec![
+        MockResponse::status(429, r#"{"error":29,"message":"Rate limit exceeded"}"#),
+        MockResponse::json(TOP_TAGS),
+    ])
+    .await;
+    let client = client(
+        &server,
+        RateLimit {
+            requests_per_second: 100.0,
+            burst: 10,
+            ..RateLimit::default()
+        },
+    );
+
+    client.chart().get_top_tags(None, None).await.unwrap_err();
+
+    let state = client.rate_limiter().unwrap().state();
+    assert_eq!(state.current_rate, 50.0);
+    assert_eq!(state.max_rate, 100.0);
+    assert_eq!(state.rate_limited, 1);
+
+    client.chart().get_top_tags(None, None).await.unwrap();
+    assert_eq!(client.rate_limiter().unwrap().state().current_rate, 55.0);
+}
+
+#[tokio::test]
+async fn test_rate_limit_slows_down_queued_requests() {
+    let server = MockServer::start(vec![
+        MockResponse::status(429, r#"{"error":29,"message":"Rate limit exceeded"}"#),
+        MockResponse::json(TOP_TAGS),
+    ])
+    .await;
+    let client = client(
+        &server,
+        RateLimit {
+            requests_per_second: 20.0,
+            burst: 1,
+            ..RateLimit::default()
+        },
+    );
+
+    let started = Instant::now();
+    let requests = (0..3).map(|_| async { client.chart().get_top_tags(None, None).await });
+    futures::future::join_all(requests).await;
+
+    // The first request uses the burst and halves the rate while the other two are
+    // queued; they then wait about 100ms each instead of 50ms.
+    assert!(started.elapsed() >= Duration::from_millis(170));
+    assert_eq!(server.requests().len(), 3);
+}
+
+#[tokio::test]
+async fn test_no_rate_limiter_by_default() {
+    let server = MockServer::start(vec![MockResponse::json(TOP_TAGS)]).await;
+
+    assert!(server.client().rate_limiter().is_none());
+}
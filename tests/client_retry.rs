@@ -0,0 +1,202 @@
+mod common;
+
+use std::time::{Duration, Instant};
+
+use chrono::Utc;
+use common::{MockResponse, MockServer};
+use soniq::auth::Session;
+use soniq::client::Client;
+use soniq::endpoints::track::Scrobble;
+use soniq::retry::RetryPolicy;
+
+const USER_INFO: &str = r##"{"user":{"name":"tester","age":"0","subscriber":"0","realname":"","bootstrap":"0","playcount":"1","artist_count":"1","playlists":"0","track_count":"1","album_count":"1","image":[],"registered":{"unixtime":"1100000000","#text":1100000000},"country":"None","gender":"n","url":"https://www.last.fm/user/tester","type":"user"}}"##;
+
+fn client(server: &MockServer, respect_retry_after: bool) -> Client {
+    Client::builder("test_api_key")
+        .api_secret("test_api_secret")
+        .base_url(&server.url)
+        .unwrap()
+        .retry_policy(RetryPolicy {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(1),
+            max_backoff: Duration::from_secs(2),
+            jitter: false,
+            respect_retry_after,
+            ..RetryPolicy::default()
+        })
+        .build()
+        .unwrap()
+}
+
+fn session() -> Session {
+    Session {
+        name: "tester".into(),
+        key: "session_key".into(),
+        subscriber: 0,
+    }
+}
+
+#[tokio::test]
+async fn test_retries_transient_errors() {
+    let server = MockServer::start(vec![
+        MockResponse::status(503, "<html>Service Unavailable</html>"),
+        MockResponse::status(500, r#"{"error":16,"message":"Temporarily unavailable"}"#),
+        MockResponse::json(USER_INFO),
+    ])
+    .await;
+
+    let user = client(&server, false)
+        .user()
+        .get_info("tester")
+        .await
+        .expect("Request should succeed after retries");
+
+    assert_eq!(user.name, "tester");
+    assert_eq!(server.requests().len(), 3);
+}
+
+#[tokio::test]
+async fn test_gives_up_after_max_attempts() {
+    let server = MockServer::start(vec![MockResponse::status(
+        500,
+        r#"{"error":8,"message":"Operation failed"}"#,
+    )])
+    .await;
+
+    let err = client(&server, false)
+        .user()
+        .get_info("tester")
+        .await
+        .unwrap_err();
+
+    assert!(matches!(err, soniq::Error::LastFm(ref e) if e.error == 8));
+    assert_eq!(server.requests().len(), 3);
+}
+
+#[tokio::test]
+async fn test_does_not_retry_client_errors() {
+    let server = MockServer::start(vec![MockResponse::status(
+        400,
+        r#"{"error":6,"message":"User not found"}"#,
+    )])
+    .await;
+
+    let err = client(&server, false)
+        .user()
+        .get_info("nobody")
+        .await
+        .unwrap_err();
+
+    assert!(matches!(err, soniq::Error::LastFm(ref e) if e.error == 6));
+    assert_eq!(server.requests().len(), 1);
+}
+
+#[tokio::test]
+async fn test_does_not_repeat_writes_that_may_have_applied() {
+    let server = MockServer::start(vec![MockResponse::status(
+        500,
+        r#"{"error":8,"message":"Operation failed"}"#,
+    )])
+    .await;
+    let client = client(&server, false).authenticate(session()).unwrap();
+
+    client
+        .track()
+        .scrobble(&Scrobble::new("Low", "Words", Utc::now()))
+        .await
+        .unwrap_err();
+
+    assert_eq!(server.requests().len(), 1);
+}
+
+#[tokio::test]
+async fn test_does_not_repeat_token_exchanges() {
+    let server = MockServer::start(vec![MockResponse::status(
+        500,
+        r#"{"error":8,"message":"Operation failed"}"#,
+    )])
+    .await;
+
+    client(&server, false)
+        .get_session("approved_token")
+        .await
+        .unwrap_err();
+
+    assert_eq!(server.requests().len(), 1);
+}
+
+#[tokio::test]
+async fn test_retries_rate_limited_writes() {
+    let server = MockServer::start(vec![
+        MockResponse::status(429, r#"{"error":29,"message":"Rate limit exceeded"}"#),
+        MockResponse::json("{}"),
+    ])
+    .await;
+    let client = client(&server, false).authenticate(session()).unwrap();
+
+    client
+        .track()
+        .love("Low", "Words")
+        .await
+        .expect("Love should succeed after the rate limit clears");
+
+    assert_eq!(server.requests().len(), 2);
+}
+
+#[tokio::test]
+async fn test_honors_retry_after() {
+    let server = MockServer::start(vec![
+        MockResponse::status(429, r#"{"error":29,"message":"Rate limit exceeded"}"#)
+            .header("Retry-After", "1"),
+        MockResponse::json(USER_INFO),
+    ])
+    .await;
+
+    let started = Instant::now();
+    client(&server, true)
+        .user()
+        .get_info("tester")
+        .await
+        .expect("Request should succeed after waiting");
+
+    assert!(started.elapsed() >= Duration::from_secs(1));
+}
+
+#[tokio::test]
+async fn test_gives_up_when_retry_after_exceeds_max_backoff() {
+    let server = MockServer::start(vec![
+        MockResponse::status(503, r#"{"error":16,"message":"Temporary error"}"#)
+            .header("Retry-After", "86400"),
+        MockResponse::json(USER_INFO),
+    ])
+    .await;
+
+    let started = Instant::now();
+    let err = client(&server, true)
+        .user()
+        .get_info("tester")
+        .await
+        .unwrap_err();
+
+    assert!(matches!(err, soniq::Error::LastFm(ref e) if e.error == 16));
+    assert_eq!(server.requests().len(), 1);
+    assert!(started.elapsed() < Duration::from_secs(1));
+}
+
+#[tokio::test]
+async fn test_does_not_retry_html_client_errors() {
+    let server = MockServer::start(vec![
+        MockResponse::status(404, "<html><head><title>Not Found</title></head></html>"),
+        MockResponse::json(USER_INFO),
+    ])
+    .await;
+
+    let err = client(&server, false)
+        .user()
+        .get_info("tester")
+        .await
+        .unwrap_err();
+
+    assert!(matches!(err, soniq::Error::HtmlPage { .. }));
+    assert_eq!(server.requests().len(), 1);
+}
@@ -0,0 +1,182 @@
+//! A minimal local stand-in for the Last.fm API, used by tests that run offline.
+//!
+//! The server answers every request with the next canned response (repeating the
+//! last one once exhausted) and records what it received.
+
+#![allow(dead_code)]
+
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex};
+
+use soniq::auth::Session;
+use soniq::client::{AuthenticatedClient, Client};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+
+/// A canned HTTP response.
+#[derive(Clone, Debug)]
+pub struct MockResponse {
+    pub status: u16,
+    pub headers: Vec<(String, String)>,
+    pub body: String,
+}
+
+impl MockResponse {
+    /// A `200 OK` response with a JSON body.
+    pub fn json(body: impl Into<String>) -> Self {
+        Self::status(200, body)
+    }
+
+    /// A response with the given status code and body.
+    pub fn status(status: u16, body: impl Into<String>) -> Self {
+        Self {
+            status,
+            headers: Vec::new(),
+            body: body.into(),
+        }
+    }
+
+    /// Adds a response header.
+    pub fn header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+}
+
+/// A request captured by [`MockServer`].
+#[derive(Clone, Debug)]
+pub struct RecordedRequest {
+    pub method: String,
+    pub target: String,
+    pub body: String,
+}
+
+impl RecordedRequest {
+    /// Decoded parameters from both the query string and a form-encoded body.
+    pub fn params(&self) -> BTreeMap<String, String> {
+        let query = self.target.split_once('?').map(|(_, q)| q).unwrap_or("");
+        url::form_urlencoded::parse(query.as_bytes())
+            .chain(url::form_urlencoded::parse(self.body.as_bytes()))
+            .map(|(k, v)| (k.into_owned(), v.into_owned()))
+            .collect()
+    }
+}
+
+/// A running stand-in server.
+pub struct MockServer {
+    pub url: String,
+    requests: Arc<Mutex<Vec<RecordedRequest>>>,
+}
+
+impl MockServer {
+    /// Starts a server that replies with `responses` in order.
+    pub async fn start(responses: Vec<MockResponse>) -> Self {
+        assert!(!responses.is_empty(), "at least one response is required");
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/2.0/", listener.local_addr().unwrap());
+        let requests = Arc::new(Mutex::new(Vec::new()));
+
+        let recorded = Arc::clone(&requests);
+        tokio::spawn(async move {
+            let mut served = 0;
+            loop {
+                let Ok((mut stream, _)) = listener.accept().await else {
+                    return;
+                };
+
+                let Some(request) = read_request(&mut stream).await else {
+                    continue;
+                };
+                recorded.lock().unwrap().push(request);
+
+                let response = &responses[served.min(responses.len() - 1)];
+                served += 1;
+
+                let mut raw = format!(
+                    "HTTP/1.1 {} Mock\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n",
+                    response.status,
+                    response.body.len()
+                );
+                for (name, value) in &response.headers {
+                    raw.push_str(&format!("{name}: {value}\r\n"));
+                }
+                raw.push_str("\r\n");
+                raw.push_str(&response.body);
+
+                let _ = stream.write_all(raw.as_bytes()).await;
+                let _ = stream.shutdown().await;
+            }
+        });
+
+        Self { url, requests }
+    }
+
+    /// A client pointed at this server, with an API secret for signed calls.
+    pub fn client(&self) -> Client {
+        Client::builder("test_api_key")
+            .api_secret("test_api_secret")
+            .base_url(&self.url)
+            .unwrap()
+            .build()
+            .unwrap()
+    }
+
+    /// A client pointed at this server, bound to session `session_key` of user `tester`.
+    pub fn authenticated_client(&self) -> AuthenticatedClient {
+        let session = Session {
+            name: "tester".into(),
+            key: "session_key".into(),
+            subscriber: 0,
+        };
+        self.client().authenticate(session).unwrap()
+    }
+
+    /// All requests received so far.
+    pub fn requests(&self) -> Vec<RecordedRequest> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+async fn read_request(stream: &mut tokio::net::TcpStream) -> Option<RecordedRequest> {
+    let mut buf = Vec::new();
+    let mut chunk = [0u8; 4096];
+
+    let head_end = loop {
+        let n = stream.read(&mut chunk).await.ok()?;
+        if n == 0 {
+            return None;
+        }
+        buf.extend_from_slice(&chunk[..n]);
+        if let Some(pos) = buf.windows(4).position(|w| w == b"\r\n\r\n") {
+            break pos + 4;
+        }
+    };
+
+    let head = String::from_utf8_lossy(&buf[..head_end]).into_owned();
+    let mut lines = head.lines();
+    let mut request_line = lines.next()?.split_whitespace();
+    let method = request_line.next()?.to_string();
+    let target = request_line.next()?.to_string();
+
+    let content_length = lines
+        .filter_map(|line| line.split_once(':'))
+        .find(|(name, _)| name.eq_ignore_ascii_case("content-length"))
+        .and_then(|(_, value)| value.trim().parse::<usize>().ok())
+        .unwrap_or(0);
+
+    while buf.len() < head_end + content_length {
+        let n = stream.read(&mut chunk).await.ok()?;
+        if n == 0 {
+            break;
+        }
+        buf.extend_from_slice(&chunk[..n]);
+    }
+
+    let body = String::from_utf8_lossy(&buf[head_end..]).into_owned();
+    Some(RecordedRequest {
+        method,
+        target,
+        body,
+    })
+}
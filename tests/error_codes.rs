@@ -0,0 +1,47 @@
+mod common;
+
+use common::{MockResponse, MockServer};
+use soniq::error::LastFmErrorCode;
+
+#[test]
+fn test_error_code_roundtrip() {
+    for code in 0..=40 {
+        assert_eq!(LastFmErrorCode::from(code).as_u32(), code);
+    }
+
+    assert_eq!(LastFmErrorCode::from(2), LastFmErrorCode::InvalidService);
+    assert_eq!(
+        LastFmErrorCode::from(29),
+        LastFmErrorCode::RateLimitExceeded
+    );
+    assert_eq!(LastFmErrorCode::from(19), LastFmErrorCode::Unknown(19));
+}
+
+#[test]
+fn test_error_code_classification() {
+    let retryable: Vec<u32> = (0..=40)
+        .filter(|&code| LastFmErrorCode::from(code).is_retryable())
+        .collect();
+    assert_eq!(retryable, [8, 11, 16, 29]);
+
+    assert!(LastFmErrorCode::InvalidSessionKey.is_auth_error());
+    assert!(LastFmErrorCode::InvalidSessionKey.is_client_error());
+    assert!(LastFmErrorCode::InvalidParameters.is_client_error());
+    assert!(!LastFmErrorCode::InvalidParameters.is_auth_error());
+    assert!(!LastFmErrorCode::RateLimitExceeded.is_client_error());
+    assert!(!LastFmErrorCode::Unknown(99).is_client_error());
+}
+
+#[tokio::test]
+async fn test_error_exposes_code() {
+    let server = MockServer::start(vec![MockResponse::status(
+        400,
+        r#"{"error":6,"message":"User not found"}"#,
+    )])
+    .await;
+
+    let err = server.client().user().get_info("nobody").await.unwrap_err();
+
+    assert_eq!(err.code(), Some(LastFmErrorCode::InvalidParameters));
+    assert!(!err.code().unwrap().is_retryable());
+}
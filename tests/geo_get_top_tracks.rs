@@ -0,0 +1,29 @@
+mod common;
+
+use common::{MockResponse, MockServer};
+
+#[tokio::test]
+async fn test_geo_get_top_tracks() {
+    let body = r##"{"tracks":{"track":[
+        {"name":"Flowers","duration":"200","listeners":"12345","mbid":"","url":"https://www.last.fm/music/Miley+Cyrus/_/Flowers","streamable":{"#text":"0","fulltrack":"0"},"artist":{"name":"Miley Cyrus","mbid":"7e9bd05a-117f-4cce-87bc-e011527a8b18","url":"https://www.last.fm/music/Miley+Cyrus"},"image":[{"#text":"","size":"small"}],"@attr":{"rank":"0"}}
+    ],"@attr":{"country":"Spain","page":"1","perPage":"1","totalPages":"10000","total":"10000"}}}"##;
+    let server = MockServer::start(vec![MockResponse::json(body)]).await;
+
+    let chart = server
+        .client()
+        .geo()
+        .get_top_tracks("Spain", Some("Madrid"), Some(1), None)
+        .await
+        .expect("Failed to fetch geo top tracks");
+
+    assert_eq!(chart.attr.country.as_deref(), Some("Spain"));
+    assert!(chart.attr.user.is_none());
+    assert_eq!(chart.track[0].duration, Some(200));
+    assert_eq!(chart.track[0].artist.name, "Miley Cyrus");
+
+    let params = server.requests()[0].params();
+    assert_eq!(params["method"], "geo.getTopTracks");
+    assert_eq!(params["country"], "Spain");
+    assert_eq!(params["location"], "Madrid");
+    assert!(!params.contains_key("page"));
+}
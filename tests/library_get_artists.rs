@@ -0,0 +1,24 @@
+use std::env;
+
+use soniq::client::Client;
+
+#[tokio::test]
+async fn test_library_get_artists() {
+    dotenv::dotenv().expect("Failed to load .env file");
+
+    let api_key = env::var("LASTFM_API_KEY").expect("Set LASTFM_API_KEY env var");
+
+    let client = Client::builder(api_key)
+        .build()
+        .expect("Failed to build client");
+
+    let artists = client
+        .library()
+        .get_artists("RJ", Some(10), None)
+        .await
+        .expect("Failed to fetch library artists");
+
+    assert_eq!(artists.attr.user.as_deref(), Some("RJ"));
+    assert!(artists.attr.total > 0, "Expected non-empty library");
+    assert!(artists.artist[0].playcount > 0);
+}
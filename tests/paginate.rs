@@ -0,0 +1,161 @@
+mod common;
+
+use common::{MockResponse, MockServer};
+use futures::{StreamExt, TryStreamExt};
+use soniq::client::Client;
+use soniq::paginate::paginate;
+
+fn loved_page(page: u32, total_pages: u32, names: &[&str]) -> MockResponse {
+    let tracks: Vec<String> = names
+        .iter()
+        .map(|name| {
+            format!(
+                r##"{{"artist":{{"url":"https://www.last.fm/music/Low","name":"Low","mbid":""}},"date":{{"uts":"1700000000","#text":"14 Nov 2023, 22:13"}},"mbid":"","url":"https://www.last.fm/music/Low/_/{name}","name":"{name}","image":[],"streamable":{{"fulltrack":"0","#text":"0"}}}}"##
+            )
+        })
+        .collect();
+
+    MockResponse::json(format!(
+        r#"{{"lovedtracks":{{"track":[{}],"@attr":{{"user":"tester","totalPages":"{total_pages}","page":"{page}","perPage":"2","total":"{}"}}}}}}"#,
+        tracks.join(","),
+        total_pages * 2
+    ))
+}
+
+async fn loved_names(
+    client: &Client,
+    max_items: Option<usize>,
+) -> Result<Vec<String>, soniq::Error> {
+    let stream = paginate(move |page| async move {
+        client
+            .user()
+            .get_loved_tracks_page("tester", Some(2), Some(page))
+            .await
+    });
+    let stream = match max_items {
+        Some(max) => stream.max_items(max),
+        None => stream,
+    };
+
+    stream.map_ok(|track| track.name).try_collect().await
+}
+
+fn requested_pages(server: &MockServer) -> Vec<String> {
+    server
+        .requests()
+        .iter()
+        .map(|r| r.params()["page"].clone())
+        .collect()
+}
+
+#[tokio::test]
+async fn test_paginate_all_pages() {
+    let server = MockServer::start(vec![
+        loved_page(1, 3, &["Words", "Lullaby"]),
+        loved_page(2, 3, &["Laser Beam", "Over the Ocean"]),
+        loved_page(3, 3, &["Sunflower"]),
+    ])
+    .await;
+
+    let names = loved_names(&server.client(), None).await.unwrap();
+
+    assert_eq!(
+        names,
+        [
+            "Words",
+            "Lullaby",
+            "Laser Beam",
+            "Over the Ocean",
+            "Sunflower"
+        ]
+    );
+    assert_eq!(requested_pages(&server), ["1", "2", "3"]);
+}
+
+#[tokio::test]
+async fn test_paginate_max_items() {
+    let server = MockServer::start(vec![
+        loved_page(1, 3, &["Words", "Lullaby"]),
+        loved_page(2, 3, &["Laser Beam", "Over the Ocean"]),
+        loved_page(3, 3, &["Sunflower"]),
+    ])
+    .await;
+
+    let names = loved_names(&server.client(), Some(3)).await.unwrap();
+
+    assert_eq!(names, ["Words", "Lullaby", "Laser Beam"]);
+    assert_eq!(requested_pages(&server), ["1", "2"]);
+}
+
+#[tokio::test]
+async fn test_paginate_stops_on_repeated_last_page() {
+    // The list shrank while it was read, so page 3 comes back as a repeat of page 2.
+    let server = MockServer::start(vec![
+        loved_page(1, 3, &["Words", "Lullaby"]),
+        loved_page(2, 3, &["Laser Beam", "Over the Ocean"]),
+        loved_page(2, 2, &["Laser Beam", "Over the Ocean"]),
+    ])
+    .await;
+
+    let names = loved_names(&server.client(), None).await.unwrap();
+
+    assert_eq!(names, ["Words", "Lullaby", "Laser Beam", "Over the Ocean"]);
+    assert_eq!(requested_pages(&server), ["1", "2", "3"]);
+}
+
+#[tokio::test]
+async fn test_paginate_stops_after_error() {
+    let server = MockServer::start(vec![
+        loved_page(1, 3, &["Words", "Lullaby"]),
+        MockResponse::status(500, r#"{"error":8,"message":"Operation failed"}"#),
+    ])
+    .await;
+    let client = server.client();
+
+    let items: Vec<_> = paginate(|page| {
+        let client = &client;
+        async move {
+            client
+                .user()
+                .get_loved_tracks_page("tester", Some(2), Some(page))
+                .await
+        }
+    })
+    .collect()
+    .await;
+
+    assert_eq!(items.len(), 3);
+    assert!(items[..2].iter().all(Result::is_ok));
+    assert!(matches!(items[2], Err(soniq::Error::LastFm(ref e)) if e.error == 8));
+}
+
+#[tokio::test]
+async fn test_paginate_skips_repeated_now_playing() {
+    // Last.fm puts the now-playing track at the top of every page.
+    let now_playing = r##"{"artist":{"mbid":"","#text":"Broadcast"},"streamable":"0","image":[],"mbid":"","album":{"mbid":"","#text":"Tender Buttons"},"name":"America's Boy","@attr":{"nowplaying":"true"},"url":"https://www.last.fm/music/Broadcast/_/America%27s+Boy"}"##;
+    let page = |page: u32, name: &str, uts: u32| {
+        MockResponse::json(format!(
+            r##"{{"recenttracks":{{"track":[{now_playing},{{"artist":{{"mbid":"","#text":"Stereolab"}},"streamable":"0","image":[],"mbid":"","album":{{"mbid":"","#text":"Dots and Loops"}},"name":"{name}","url":"https://www.last.fm/music/Stereolab/_/{name}","date":{{"uts":"{uts}","#text":""}}}}],"@attr":{{"user":"tester","totalPages":"2","page":"{page}","perPage":"1","total":"2"}}}}}}"##
+        ))
+    };
+    let server = MockServer::start(vec![
+        page(1, "Brakhage", 1_700_000_100),
+        page(2, "Contronatura", 1_700_000_000),
+    ])
+    .await;
+    let client = server.client();
+    let client = &client;
+
+    let names: Vec<String> = paginate(move |page| async move {
+        client
+            .user()
+            .get_recent_tracks("tester", None, None, Some(1), Some(page), false)
+            .await
+    })
+    .map_ok(|track| track.track().name.clone())
+    .try_collect()
+    .await
+    .unwrap();
+
+    assert_eq!(names, ["America's Boy", "Brakhage", "Contronatura"]);
+}
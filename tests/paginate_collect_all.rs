@@ -0,0 +1,139 @@
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+
+use soniq::models::user::{LovedTracks, RecentTracks};
+use soniq::paginate::paginate;
+
+fn loved_page(page: u32, total_pages: u32, names: &[&str]) -> LovedTracks {
+    let tracks: Vec<String> = names
+        .iter()
+        .map(|name| {
+            format!(
+                r##"{{"artist":{{"url":"https://www.last.fm/music/Low","name":"Low","mbid":""}},"date":{{"uts":"1700000000","#text":""}},"mbid":"","url":"https://www.last.fm/music/Low/_/{name}","name":"{name}","image":[],"streamable":{{"fulltrack":"0","#text":"0"}}}}"##
+            )
+        })
+        .collect();
+
+    serde_json::from_str(&format!(
+        r#"{{"track":[{}],"@attr":{{"user":"tester","totalPages":"{total_pages}","page":"{page}","perPage":"2","total":"{}"}}}}"#,
+        tracks.join(","),
+        total_pages * 2
+    ))
+    .unwrap()
+}
+
+#[tokio::test]
+async fn test_collect_all_keeps_order_with_bounded_concurrency() {
+    let in_flight = AtomicUsize::new(0);
+    let max_in_flight = AtomicUsize::new(0);
+    let requested = Mutex::new(Vec::new());
+
+    let names: Vec<String> = paginate(|page| {
+        requested.lock().unwrap().push(page);
+        let (in_flight, max_in_flight) = (&in_flight, &max_in_flight);
+        async move {
+            let now = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+            max_in_flight.fetch_max(now, Ordering::SeqCst);
+            // Later pages answer first.
+            tokio::time::sleep(Duration::from_millis(u64::from(10 - page) * 5)).await;
+            in_flight.fetch_sub(1, Ordering::SeqCst);
+
+            let a = format!("p{page}a");
+            let b = format!("p{page}b");
+            Ok(loved_page(page, 6, &[&a, &b]))
+        }
+    })
+    .collect_all(3)
+    .await
+    .unwrap()
+    .into_iter()
+    .map(|track| track.name)
+    .collect();
+
+    let expected: Vec<String> = (1..=6)
+        .flat_map(|p| [format!("p{p}a"), format!("p{p}b")])
+        .collect();
+    assert_eq!(names, expected);
+    assert_eq!(*requested.lock().unwrap(), [1, 2, 3, 4, 5, 6]);
+    assert!(max_in_flight.load(Ordering::SeqCst) <= 3);
+}
+
+#[tokio::test]
+async fn test_collect_all_drops_shifted_duplicates() {
+    // A new track was loved after page 1 was read, pushing "Lullaby" onto page 2.
+    let names: Vec<String> = paginate(|page| async move {
+        Ok(match page {
+            1 => loved_page(1, 3, &["Words", "Lullaby"]),
+            2 => loved_page(2, 3, &["Lullaby", "Laser Beam"]),
+            3 => loved_page(3, 3, &["Over the Ocean"]),
+            _ => loved_page(3, 3, &["Over the Ocean"]),
+        })
+    })
+    .collect_all(4)
+    .await
+    .unwrap()
+    .into_iter()
+    .map(|track| track.name)
+    .collect();
+
+    assert_eq!(names, ["Words", "Lullaby", "Laser Beam", "Over the Ocean"]);
+}
+
+#[tokio::test]
+async fn test_collect_all_respects_max_items() {
+    let requested = Mutex::new(Vec::new());
+
+    let tracks = paginate(|page| {
+        requested.lock().unwrap().push(page);
+        async move {
+            let a = format!("p{page}a");
+            let b = format!("p{page}b");
+            Ok(loved_page(page, 10, &[&a, &b]))
+        }
+    })
+    .max_items(3)
+    .collect_all(4)
+    .await
+    .unwrap();
+
+    assert_eq!(tracks.len(), 3);
+    assert_eq!(*requested.lock().unwrap(), [1, 2]);
+}
+
+#[tokio::test]
+async fn test_collect_all_keeps_repeated_plays() {
+    let page = |n: u32, uts: &[u32]| -> RecentTracks {
+        let tracks: Vec<String> = uts
+            .iter()
+            .map(|uts| {
+                format!(
+                    r##"{{"artist":{{"mbid":"","#text":"Low"}},"streamable":"0","mbid":"","album":{{"mbid":"","#text":"Things We Lost in the Fire"}},"url":"https://www.last.fm/music/Low/_/Sunflower","name":"Sunflower","image":[],"date":{{"uts":"{uts}","#text":""}}}}"##
+                )
+            })
+            .collect();
+        serde_json::from_str(&format!(
+            r#"{{"track":[{}],"@attr":{{"user":"tester","totalPages":"2","page":"{n}","perPage":"2","total":"4"}}}}"#,
+            tracks.join(",")
+        ))
+        .unwrap()
+    };
+
+    let plays = paginate(|n| {
+        let response = if n == 1 {
+            page(1, &[1_700_000_300, 1_700_000_200])
+        } else {
+            page(2, &[1_700_000_200, 1_700_000_100])
+        };
+        async move { Ok(response) }
+    })
+    .collect_all(2)
+    .await
+    .unwrap();
+
+    let times: Vec<_> = plays
+        .iter()
+        .map(|p| p.date().unwrap().timestamp())
+        .collect();
+    assert_eq!(times, [1_700_000_300, 1_700_000_200, 1_700_000_100]);
+}
@@ -0,0 +1,110 @@
+mod common;
+
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use common::{MockResponse, MockServer};
+use soniq::auth::Session;
+use soniq::client::Client;
+use soniq::session::{FileSessionStore, MemorySessionStore, SessionStore};
+
+fn session() -> Session {
+    Session {
+        name: "tester".into(),
+        key: "d580d57f32848f5dcf574d1ce18d78b2".into(),
+        subscriber: 0,
+    }
+}
+
+fn temp_path(name: &str) -> PathBuf {
+    std::env::temp_dir()
+        .join(format!("soniq-test-{}", std::process::id()))
+        .join(name)
+}
+
+#[tokio::test]
+async fn test_file_session_store_roundtrip() {
+    let store = FileSessionStore::new(temp_path("roundtrip.json"));
+
+    assert_eq!(store.load().await.unwrap(), None);
+
+    store.save(&session()).await.unwrap();
+    assert_eq!(store.load().await.unwrap(), Some(session()));
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+
+        let mode = std::fs::metadata(store.path())
+            .unwrap()
+            .permissions()
+            .mode();
+        assert_eq!(mode & 0o777, 0o600);
+    }
+
+    store.clear().await.unwrap();
+    assert_eq!(store.load().await.unwrap(), None);
+    store.clear().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_memory_session_store() {
+    let store = MemorySessionStore::new();
+
+    assert_eq!(store.load().await.unwrap(), None);
+    store.save(&session()).await.unwrap();
+    assert_eq!(store.load().await.unwrap(), Some(session()));
+    store.clear().await.unwrap();
+    assert_eq!(store.load().await.unwrap(), None);
+}
+
+#[tokio::test]
+async fn test_build_with_stored_session() {
+    let store = MemorySessionStore::new();
+    store.save(&session()).await.unwrap();
+
+    let client = Client::builder("test_api_key")
+        .api_secret("test_api_secret")
+        .build_with_session(&store, |_| async { panic!("auth flow should not run") })
+        .await
+        .expect("Failed to build client");
+
+    assert_eq!(client.session(), &session());
+}
+
+#[tokio::test]
+async fn test_build_with_session_falls_back_to_auth_flow() {
+    let body =
+        r#"{"session":{"name":"tester","key":"d580d57f32848f5dcf574d1ce18d78b2","subscriber":0}}"#;
+    let server = MockServer::start(vec![MockResponse::json(body)]).await;
+    let store = MemorySessionStore::new();
+
+    let client = Client::builder("test_api_key")
+        .api_secret("test_api_secret")
+        .base_url(&server.url)
+        .unwrap()
+        .build_with_session(&store, |client| async move {
+            client.get_session("approved_token").await
+        })
+        .await
+        .expect("Failed to build client");
+
+    assert_eq!(client.username(), "tester");
+    assert_eq!(store.load().await.unwrap(), Some(session()));
+}
+
+#[tokio::test]
+async fn test_build_with_session_requires_api_secret() {
+    let ran = AtomicBool::new(false);
+
+    let err = Client::builder("test_api_key")
+        .build_with_session(&MemorySessionStore::new(), |_| async {
+            ran.store(true, Ordering::SeqCst);
+            Ok(session())
+        })
+        .await
+        .unwrap_err();
+
+    assert!(matches!(err, soniq::Error::MissingApiSecret));
+    assert!(!ran.load(Ordering::SeqCst));
+}
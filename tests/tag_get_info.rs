@@ -0,0 +1,28 @@
+mod common;
+
+use common::{MockResponse, MockServer};
+
+#[tokio::test]
+async fn test_tag_get_info() {
+    let body = r#"{"tag":{"name":"disco","total":169028,"reach":40564,"wiki":{"summary":"Disco is a genre of dance music.","content":"Disco is a genre of dance music that originated in the 1970s."}}}"#;
+    let server = MockServer::start(vec![MockResponse::json(body)]).await;
+
+    let tag = server
+        .client()
+        .tag()
+        .get_info("disco", Some("en"))
+        .await
+        .expect("Failed to fetch tag info");
+
+    assert_eq!(tag.name, "disco");
+    assert_eq!(tag.total, 169028);
+    assert_eq!(tag.reach, 40564);
+
+    let wiki = tag.wiki.expect("Expected a wiki");
+    assert!(wiki.published.is_none());
+    assert!(wiki.summary.starts_with("Disco"));
+
+    let params = server.requests()[0].params();
+    assert_eq!(params["tag"], "disco");
+    assert_eq!(params["lang"], "en");
+}
@@ -0,0 +1,21 @@
+mod common;
+
+use chrono::{TimeZone, Utc};
+use common::{MockResponse, MockServer};
+
+#[tokio::test]
+async fn test_tag_get_weekly_chart_list() {
+    let body = r##"{"weeklychartlist":{"chart":[{"#text":"","from":"1108296000","to":"1108900800"},{"#text":"","from":"1108900800","to":"1109505600"}],"@attr":{"tag":"disco"}}}"##;
+    let server = MockServer::start(vec![MockResponse::json(body)]).await;
+
+    let charts = server
+        .client()
+        .tag()
+        .get_weekly_chart_list("disco")
+        .await
+        .expect("Failed to fetch weekly chart list");
+
+    assert_eq!(charts.len(), 2);
+    assert_eq!(charts[0].from, Utc.timestamp_opt(1108296000, 0).unwrap());
+    assert_eq!(charts[0].to, charts[1].from);
+}
@@ -0,0 +1,60 @@
+mod common;
+
+use common::{MockResponse, MockServer};
+use soniq::endpoints::track::TrackLookup;
+
+#[tokio::test]
+async fn test_track_get_info() {
+    let body = r##"{"track":{"name":"Believe","mbid":"32ca187e-ee25-4f18-b7d0-3b6713f24635","url":"https://www.last.fm/music/Cher/_/Believe","duration":"240000","streamable":{"#text":"0","fulltrack":"0"},"listeners":"1040366","playcount":"8788421",
+        "artist":{"name":"Cher","mbid":"bfcc6d75-a6a5-4bc6-8282-47aec8531818","url":"https://www.last.fm/music/Cher"},
+        "album":{"artist":"Cher","title":"Believe","mbid":"63b3a8ca-26f2-4e2b-b867-647a6ec2bebd","url":"https://www.last.fm/music/Cher/Believe","image":[{"#text":"https://lastfm.freetls.fastly.net/i/u/34s/1.png","size":"small"}],"@attr":{"position":"1"}},
+        "userplaycount":"12","userloved":"1","toptags":{"tag":[{"name":"pop","url":"https://www.last.fm/tag/pop"}]},"wiki":{"published":"29 Jul 2008, 15:55","summary":"Believe is a song.","content":"Believe is a song by Cher."}}}"##;
+    let server = MockServer::start(vec![MockResponse::json(body)]).await;
+
+    let track = server
+        .client()
+        .track()
+        .get_info(("Cher", "Believe"), true, Some("tester"))
+        .await
+        .expect("Failed to fetch track info");
+
+    assert_eq!(track.name, "Believe");
+    assert_eq!(track.duration, Some(240000));
+    assert_eq!(track.artist.name, "Cher");
+    assert_eq!(
+        track.album.as_ref().map(|a| a.title.as_str()),
+        Some("Believe")
+    );
+    assert_eq!(track.userplaycount, Some(12));
+    assert_eq!(track.userloved, Some(true));
+    assert_eq!(track.toptags.tag.len(), 1);
+
+    let params = server.requests()[0].params();
+    assert_eq!(params["method"], "track.getInfo");
+    assert_eq!(params["artist"], "Cher");
+    assert_eq!(params["track"], "Believe");
+    assert_eq!(params["autocorrect"], "1");
+    assert_eq!(params["username"], "tester");
+}
+
+#[tokio::test]
+async fn test_track_get_info_by_mbid() {
+    let body = r#"{"track":{"name":"Demo","url":"https://www.last.fm/music/Nobody/_/Demo","duration":"0","listeners":"1","playcount":"1","artist":{"name":"Nobody","url":"https://www.last.fm/music/Nobody"},"toptags":""}}"#;
+    let server = MockServer::start(vec![MockResponse::json(body)]).await;
+
+    let track = server
+        .client()
+        .track()
+        .get_info(TrackLookup::mbid("some-mbid"), false, None)
+        .await
+        .expect("Failed to fetch track info");
+
+    assert!(track.album.is_none());
+    assert!(track.toptags.tag.is_empty());
+    assert_eq!(track.userplaycount, None);
+    assert_eq!(track.userloved, None);
+
+    let params = server.requests()[0].params();
+    assert_eq!(params["mbid"], "some-mbid");
+    assert!(!params.contains_key("artist"));
+}
@@ -0,0 +1,29 @@
+mod common;
+
+use common::{MockResponse, MockServer};
+
+#[tokio::test]
+async fn test_track_get_similar() {
+    let body = r##"{"similartracks":{"track":[
+        {"name":"Strong Enough","playcount":2079180,"mbid":"","match":1.0,"url":"https://www.last.fm/music/Cher/_/Strong+Enough","streamable":{"#text":"0","fulltrack":"0"},"duration":223,"artist":{"name":"Cher","mbid":"bfcc6d75-a6a5-4bc6-8282-47aec8531818","url":"https://www.last.fm/music/Cher"},"image":[]},
+        {"name":"Vogue","playcount":"1470528","match":"0.62","url":"https://www.last.fm/music/Madonna/_/Vogue","artist":{"name":"Madonna","url":"https://www.last.fm/music/Madonna"}}
+    ],"@attr":{"artist":"Cher"}}}"##;
+    let server = MockServer::start(vec![MockResponse::json(body)]).await;
+
+    let similar = server
+        .client()
+        .track()
+        .get_similar(("Cher", "Believe"), false, Some(2))
+        .await
+        .expect("Failed to fetch similar tracks");
+
+    assert_eq!(similar.track.len(), 2);
+    assert_eq!(similar.track[0].match_score, 1.0);
+    assert_eq!(similar.track[0].duration, Some(223));
+    assert_eq!(similar.track[1].match_score, 0.62);
+    assert_eq!(similar.track[1].playcount, Some(1470528));
+
+    let params = server.requests()[0].params();
+    assert_eq!(params["method"], "track.getSimilar");
+    assert_eq!(params["limit"], "2");
+}
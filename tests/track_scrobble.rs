@@ -0,0 +1,95 @@
+mod common;
+
+use chrono::{TimeZone, Utc};
+use common::{MockResponse, MockServer};
+use soniq::Error;
+use soniq::endpoints::track::{MAX_SCROBBLE_BATCH, Scrobble};
+use soniq::models::track::IgnoredCode;
+
+const BATCH_RESPONSE: &str = r##"{"scrobbles":{"scrobble":[
+    {"artist":{"corrected":"0","#text":"Boards of Canada"},"album":{"corrected":"0","#text":"Geogaddi"},"track":{"corrected":"0","#text":"Dawn Chorus"},"ignoredMessage":{"code":"0","#text":""},"albumArtist":{"corrected":"0","#text":""},"timestamp":"1700000000"},
+    {"artist":{"corrected":"1","#text":"Aphex Twin"},"album":{"corrected":"0","#text":""},"track":{"corrected":"0","#text":"Xtal"},"ignoredMessage":{"code":"3","#text":"Timestamp too old"},"albumArtist":{"corrected":"0","#text":""},"timestamp":"1000000000"}
+],"@attr":{"accepted":1,"ignored":1}}}"##;
+
+#[tokio::test]
+async fn test_track_scrobble_batch() {
+    let server = MockServer::start(vec![MockResponse::json(BATCH_RESPONSE)]).await;
+    let client = server.authenticated_client();
+
+    let mut first = Scrobble::new(
+        "Boards of Canada",
+        "Dawn Chorus",
+        Utc.timestamp_opt(1_700_000_000, 0).unwrap(),
+    );
+    first.album = Some("Geogaddi".into());
+    first.chosen_by_user = Some(true);
+    let second = Scrobble::new(
+        "aphex twin",
+        "Xtal",
+        Utc.timestamp_opt(1_000_000_000, 0).unwrap(),
+    );
+
+    let result = client
+        .track()
+        .scrobble_batch(&[first, second])
+        .await
+        .expect("Failed to scrobble");
+
+    assert_eq!(result.attr.accepted, 1);
+    assert_eq!(result.attr.ignored, 1);
+    assert!(result.scrobbles[0].is_accepted());
+    assert!(result.scrobbles[1].artist.corrected);
+    assert_eq!(
+        result.scrobbles[1].ignored_message.code,
+        IgnoredCode::TimestampTooOld
+    );
+
+    let requests = server.requests();
+    assert_eq!(requests[0].method, "POST");
+    let params = requests[0].params();
+    assert_eq!(params["method"], "track.scrobble");
+    assert_eq!(params["sk"], "session_key");
+    assert_eq!(params["artist[0]"], "Boards of Canada");
+    assert_eq!(params["album[0]"], "Geogaddi");
+    assert_eq!(params["chosenByUser[0]"], "1");
+    assert_eq!(params["track[1]"], "Xtal");
+    assert_eq!(params["timestamp[1]"], "1000000000");
+    assert!(!params.contains_key("album[1]"));
+    assert!(params.contains_key("api_sig"));
+}
+
+#[tokio::test]
+async fn test_track_scrobble_single_response() {
+    let single = r##"{"scrobbles":{"scrobble":{"artist":{"corrected":"0","#text":"Low"},"album":{"corrected":"0","#text":""},"track":{"corrected":"0","#text":"Words"},"ignoredMessage":{"code":"0","#text":""},"albumArtist":{"corrected":"0","#text":""},"timestamp":"1700000000"},"@attr":{"accepted":1,"ignored":0}}}"##;
+    let server = MockServer::start(vec![MockResponse::json(single)]).await;
+
+    let result = server
+        .authenticated_client()
+        .track()
+        .scrobble(&Scrobble::new(
+            "Low",
+            "Words",
+            Utc.timestamp_opt(1_700_000_000, 0).unwrap(),
+        ))
+        .await
+        .expect("Failed to scrobble");
+
+    assert_eq!(result.scrobbles.len(), 1);
+    assert_eq!(result.scrobbles[0].track.text, "Words");
+}
+
+#[tokio::test]
+async fn test_track_scrobble_batch_too_large() {
+    let server = MockServer::start(vec![MockResponse::json("{}")]).await;
+    let batch = vec![Scrobble::new("a", "b", Utc::now()); MAX_SCROBBLE_BATCH + 1];
+
+    let err = server
+        .authenticated_client()
+        .track()
+        .scrobble_batch(&batch)
+        .await
+        .unwrap_err();
+
+    assert!(matches!(err, Error::InvalidArgument(_)));
+    assert!(server.requests().is_empty());
+}
@@ -0,0 +1,46 @@
+mod common;
+
+use common::{MockResponse, MockServer};
+
+const LOVED_TRACKS: &str = r##"{"lovedtracks":{"track":[
+    {"artist":{"url":"https://www.last.fm/music/Slowdive","name":"Slowdive","mbid":""},"date":{"uts":"1700000000","#text":"14 Nov 2023, 22:13"},"mbid":"","url":"https://www.last.fm/music/Slowdive/_/Alison","name":"Alison","image":[],"streamable":{"fulltrack":"0","#text":"0"}},
+    {"artist":{"url":"https://www.last.fm/music/Ride","name":"Ride","mbid":""},"date":{"uts":"1690000000","#text":"22 Jul 2023, 04:26"},"mbid":"","url":"https://www.last.fm/music/Ride/_/Vapour+Trail","name":"Vapour Trail","image":[],"streamable":{"fulltrack":"0","#text":"0"}}
+],"@attr":{"user":"tester","totalPages":"1","page":"1","perPage":"1000","total":"2"}}}"##;
+
+#[tokio::test]
+async fn test_track_sync_loved_tracks() {
+    let server = MockServer::start(vec![
+        MockResponse::json(LOVED_TRACKS),
+        MockResponse::json("{}"),
+    ])
+    .await;
+
+    let report = server
+        .authenticated_client()
+        .track()
+        .sync_loved_tracks([("slowdive", "alison"), ("Lush", "Sweetness and Light")])
+        .await
+        .expect("Failed to sync loved tracks");
+
+    assert_eq!(report.unchanged, 1);
+    assert_eq!(
+        report.loved,
+        vec![("Lush".to_string(), "Sweetness and Light".to_string())]
+    );
+    assert_eq!(
+        report.unloved,
+        vec![("Ride".to_string(), "Vapour Trail".to_string())]
+    );
+
+    assert_eq!(server.requests()[0].params()["user"], "tester");
+
+    let methods: Vec<_> = server
+        .requests()
+        .iter()
+        .map(|r| r.params()["method"].clone())
+        .collect();
+    assert_eq!(
+        methods,
+        ["user.getLovedTracks", "track.love", "track.unlove"]
+    );
+}
@@ -0,0 +1,34 @@
+mod common;
+
+use common::{MockResponse, MockServer};
+use soniq::endpoints::track::NowPlaying;
+use soniq::models::track::IgnoredCode;
+
+#[tokio::test]
+async fn test_track_update_now_playing() {
+    let body = r##"{"nowplaying":{"artist":{"corrected":"1","#text":"Radiohead"},"track":{"corrected":"0","#text":"Reckoner"},"album":{"corrected":"0","#text":"In Rainbows"},"albumArtist":{"corrected":"0","#text":""},"ignoredMessage":{"code":"0","#text":""}}}"##;
+    let server = MockServer::start(vec![MockResponse::json(body)]).await;
+
+    let mut now_playing = NowPlaying::new("radiohead", "Reckoner");
+    now_playing.album = Some("In Rainbows".into());
+    now_playing.duration = Some(290);
+
+    let result = server
+        .authenticated_client()
+        .track()
+        .update_now_playing(&now_playing)
+        .await
+        .expect("Failed to update now playing");
+
+    assert!(result.is_accepted());
+    assert!(result.artist.corrected);
+    assert_eq!(result.artist.text, "Radiohead");
+    assert_eq!(result.ignored_message.code, IgnoredCode::NotIgnored);
+
+    let params = server.requests()[0].params();
+    assert_eq!(params["method"], "track.updateNowPlaying");
+    assert_eq!(params["sk"], "session_key");
+    assert_eq!(params["artist"], "radiohead");
+    assert_eq!(params["duration"], "290");
+    assert!(!params.contains_key("mbid"));
+}
@@ -1,7 +1,6 @@
 use std::env;
 
 use soniq::client::Client;
-use tokio;
 
 #[tokio::test]
 async fn test_user_get_friends() {
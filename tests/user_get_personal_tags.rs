@@ -0,0 +1,49 @@
+mod common;
+
+use common::{MockResponse, MockServer};
+use soniq::endpoints::user::TaggingType;
+use soniq::models::user::PersonalTagItems;
+
+#[tokio::test]
+async fn test_user_get_personal_tags_albums() {
+    let body = r##"{"taggings":{"albums":{"album":{"name":"Loveless","mbid":"","url":"https://www.last.fm/music/My+Bloody+Valentine/Loveless","artist":{"name":"My Bloody Valentine","mbid":"","url":"https://www.last.fm/music/My+Bloody+Valentine"},"image":[{"#text":"","size":"small"}]}},
+        "@attr":{"user":"tester","tag":"shoegaze","page":"1","perPage":"50","totalPages":"1","total":"1"}}}"##;
+    let server = MockServer::start(vec![MockResponse::json(body)]).await;
+
+    let tags = server
+        .client()
+        .user()
+        .get_personal_tags("tester", "shoegaze", TaggingType::Album, None, None)
+        .await
+        .expect("Failed to fetch personal tags");
+
+    assert_eq!(tags.attr.tag.as_deref(), Some("shoegaze"));
+    match tags.items {
+        PersonalTagItems::Albums(albums) => {
+            assert_eq!(albums.len(), 1);
+            assert_eq!(albums[0].artist.name, "My Bloody Valentine");
+        }
+        other => panic!("Expected albums, got {other:?}"),
+    }
+
+    assert_eq!(server.requests()[0].params()["taggingtype"], "album");
+}
+
+#[tokio::test]
+async fn test_user_get_personal_tags_tracks() {
+    let body = r##"{"taggings":{"tracks":{"track":[{"name":"Sometimes","duration":"319","mbid":"","url":"https://www.last.fm/music/My+Bloody+Valentine/_/Sometimes","streamable":{"#text":"0","fulltrack":"0"},"artist":{"name":"My Bloody Valentine","mbid":"","url":"https://www.last.fm/music/My+Bloody+Valentine"},"image":[]}]},
+        "@attr":{"user":"tester","tag":"shoegaze","page":"1","perPage":"50","totalPages":"1","total":"1"}}}"##;
+    let server = MockServer::start(vec![MockResponse::json(body)]).await;
+
+    let tags = server
+        .client()
+        .user()
+        .get_personal_tags("tester", "shoegaze", TaggingType::Track, None, None)
+        .await
+        .expect("Failed to fetch personal tags");
+
+    assert!(matches!(
+        tags.items,
+        PersonalTagItems::Tracks(ref tracks) if tracks[0].duration == Some(319)
+    ));
+}
@@ -0,0 +1,59 @@
+mod common;
+
+use chrono::{TimeZone, Utc};
+use common::{MockResponse, MockServer};
+use soniq::models::user::RecentTrack;
+
+#[tokio::test]
+async fn test_user_get_recent_tracks() {
+    let body = r##"{"recenttracks":{"track":[
+        {"artist":{"mbid":"","#text":"Broadcast"},"streamable":"0","image":[],"mbid":"","album":{"mbid":"","#text":"Tender Buttons"},"name":"America's Boy","@attr":{"nowplaying":"true"},"url":"https://www.last.fm/music/Broadcast/_/America%27s+Boy"},
+        {"artist":{"mbid":"","#text":"Stereolab"},"streamable":"0","image":[],"mbid":"","album":{"mbid":"","#text":"Dots and Loops"},"name":"Miss Modular","url":"https://www.last.fm/music/Stereolab/_/Miss+Modular","date":{"uts":"1700000000","#text":"14 Nov 2023, 22:13"}}
+    ],"@attr":{"user":"tester","totalPages":"100","page":"1","perPage":"1","total":"100"}}}"##;
+    let server = MockServer::start(vec![MockResponse::json(body)]).await;
+
+    let from = Utc.timestamp_opt(1_600_000_000, 0).unwrap();
+    let recent = server
+        .client()
+        .user()
+        .get_recent_tracks("tester", Some(from), None, Some(1), None, false)
+        .await
+        .expect("Failed to fetch recent tracks");
+
+    assert_eq!(recent.track.len(), 2);
+    assert!(matches!(recent.track[0], RecentTrack::NowPlaying(_)));
+    assert_eq!(recent.now_playing().unwrap().artist.name, "Broadcast");
+    assert_eq!(
+        recent.track[1].date(),
+        Some(Utc.timestamp_opt(1_700_000_000, 0).unwrap())
+    );
+    assert_eq!(recent.track[1].track().album.name, "Dots and Loops");
+    assert!(recent.track[1].track().loved.is_none());
+
+    let params = server.requests()[0].params();
+    assert_eq!(params["from"], "1600000000");
+    assert!(!params.contains_key("to"));
+    assert!(!params.contains_key("extended"));
+}
+
+#[tokio::test]
+async fn test_user_get_recent_tracks_extended() {
+    let body = r##"{"recenttracks":{"track":{"artist":{"url":"https://www.last.fm/music/Stereolab","name":"Stereolab","image":[{"size":"small","#text":""}],"mbid":"a7d3cdc8-a3ae-4b95-9c7c-a6ea5bc0c7fe"},"mbid":"","name":"Miss Modular","image":[],"streamable":"0","album":{"mbid":"","#text":"Dots and Loops"},"url":"https://www.last.fm/music/Stereolab/_/Miss+Modular","date":{"uts":"1700000000","#text":"14 Nov 2023, 22:13"},"loved":"1"},
+        "@attr":{"user":"tester","totalPages":"1","page":"1","perPage":"50","total":"1"}}}"##;
+    let server = MockServer::start(vec![MockResponse::json(body)]).await;
+
+    let recent = server
+        .client()
+        .user()
+        .get_recent_tracks("tester", None, None, None, None, true)
+        .await
+        .expect("Failed to fetch recent tracks");
+
+    assert!(recent.now_playing().is_none());
+    let track = recent.track[0].track();
+    assert_eq!(track.loved, Some(true));
+    assert_eq!(track.artist.name, "Stereolab");
+    assert!(track.artist.url.is_some());
+
+    assert_eq!(server.requests()[0].params()["extended"], "1");
+}
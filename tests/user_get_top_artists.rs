@@ -0,0 +1,48 @@
+mod common;
+
+use common::{MockResponse, MockServer};
+use soniq::endpoints::user::Period;
+
+#[tokio::test]
+async fn test_user_get_top_artists() {
+    let body = r##"{"topartists":{"artist":[
+        {"streamable":"0","image":[{"size":"small","#text":""}],"mbid":"","url":"https://www.last.fm/music/Cocteau+Twins","playcount":"1234","@attr":{"rank":"1"},"name":"Cocteau Twins"},
+        {"streamable":"0","image":[],"mbid":"","url":"https://www.last.fm/music/Slowdive","playcount":"987","@attr":{"rank":"2"},"name":"Slowdive"}
+    ],"@attr":{"page":"1","total":"321","user":"tester","perPage":"2","totalPages":"161"}}}"##;
+    let server = MockServer::start(vec![MockResponse::json(body)]).await;
+
+    let top = server
+        .client()
+        .user()
+        .get_top_artists("tester", Period::SevenDay, Some(2), None)
+        .await
+        .expect("Failed to fetch top artists");
+
+    assert_eq!(top.attr.total_pages, 161);
+    assert_eq!(top.artist[1].attr.rank, 2);
+    assert_eq!(top.artist[0].playcount, 1234);
+
+    let params = server.requests()[0].params();
+    assert_eq!(params["method"], "user.getTopArtists");
+    assert_eq!(params["period"], "7day");
+}
+
+#[test]
+fn test_period_strings() {
+    let all = [
+        (Period::Overall, "overall"),
+        (Period::SevenDay, "7day"),
+        (Period::OneMonth, "1month"),
+        (Period::ThreeMonth, "3month"),
+        (Period::SixMonth, "6month"),
+        (Period::TwelveMonth, "12month"),
+    ];
+
+    for (period, expected) in all {
+        assert_eq!(period.to_string(), expected);
+        assert_eq!(
+            serde_json::to_string(&period).unwrap(),
+            format!("\"{expected}\"")
+        );
+    }
+}
@@ -0,0 +1,52 @@
+mod common;
+
+use chrono::{TimeZone, Utc};
+use common::{MockResponse, MockServer};
+use soniq::models::user::WeeklyArtistChart;
+
+const CHART_LIST: &str = r##"{"weeklychartlist":{"chart":[
+    {"#text":"","from":"1699790400","to":"1700395200"},
+    {"#text":"","from":"1700395200","to":"1701000000"},
+    {"#text":"","from":"1701000000","to":"1701604800"}
+],"@attr":{"user":"tester"}}}"##;
+
+fn artist_chart(from: &str, to: &str) -> String {
+    format!(
+        r##"{{"weeklyartistchart":{{"artist":[{{"mbid":"","url":"https://www.last.fm/music/Low","name":"Low","@attr":{{"rank":"1"}},"playcount":"12"}}],"@attr":{{"from":"{from}","user":"tester","to":"{to}"}}}}}}"##
+    )
+}
+
+#[tokio::test]
+async fn test_user_get_weekly_charts_between() {
+    let server = MockServer::start(vec![
+        MockResponse::json(CHART_LIST),
+        MockResponse::json(artist_chart("1699790400", "1700395200")),
+        MockResponse::json(artist_chart("1700395200", "1701000000")),
+    ])
+    .await;
+
+    let charts: Vec<WeeklyArtistChart> = server
+        .client()
+        .user()
+        .get_weekly_charts_between(
+            "tester",
+            Utc.timestamp_opt(1699790400, 0).unwrap(),
+            Utc.timestamp_opt(1701000000, 0).unwrap(),
+        )
+        .await
+        .expect("Failed to fetch weekly charts");
+
+    assert_eq!(charts.len(), 2);
+    assert_eq!(
+        charts[1].attr.range.from,
+        Utc.timestamp_opt(1700395200, 0).unwrap()
+    );
+    assert_eq!(charts[0].artist[0].playcount, 12);
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 3);
+    let params = requests[2].params();
+    assert_eq!(params["method"], "user.getWeeklyArtistChart");
+    assert_eq!(params["from"], "1700395200");
+    assert_eq!(params["to"], "1701000000");
+}
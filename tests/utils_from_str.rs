@@ -0,0 +1,37 @@
+use serde::Deserialize;
+use soniq::utils::{from_str, from_str_opt};
+
+#[derive(Debug, Deserialize)]
+struct Counts {
+    #[serde(deserialize_with = "from_str")]
+    playcount: u64,
+
+    #[serde(default, deserialize_with = "from_str_opt")]
+    duration: Option<u32>,
+}
+
+#[test]
+fn test_from_str_accepts_strings_and_numbers() {
+    let quoted: Counts = serde_json::from_str(r#"{"playcount":"42","duration":"180"}"#).unwrap();
+    assert_eq!(quoted.playcount, 42);
+    assert_eq!(quoted.duration, Some(180));
+
+    let bare: Counts = serde_json::from_str(r#"{"playcount":42,"duration":180}"#).unwrap();
+    assert_eq!(bare.playcount, 42);
+    assert_eq!(bare.duration, Some(180));
+
+    let missing: Counts = serde_json::from_str(r#"{"playcount":42,"duration":null}"#).unwrap();
+    assert_eq!(missing.duration, None);
+}
+
+#[test]
+fn test_from_str_keeps_parse_error() {
+    let err = serde_json::from_str::<Counts>(r#"{"playcount":"4x2"}"#).unwrap_err();
+    assert!(err.to_string().contains("invalid digit found in string"));
+
+    let err = serde_json::from_str::<Counts>(r#"{"playcount":1,"duration":"-1"}"#).unwrap_err();
+    assert!(err.to_string().contains("invalid digit found in string"));
+
+    let err = serde_json::from_str::<Counts>(r#"{"playcount":[1]}"#).unwrap_err();
+    assert!(err.to_string().contains("a string or a number"));
+}